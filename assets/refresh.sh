@@ -1,6 +1,9 @@
 #!/bin/bash
 
 export API_KEY=$1
+export SESSION_ID=$2
+export ACCESS_TOKEN=$3
+export ACCOUNT_OBJECT_ID=$4
 
 function call() {
     if [ -z "$3" ]; then
@@ -10,12 +13,47 @@ function call() {
     fi
 }
 
+# account
+if [ -n "$SESSION_ID" ]; then
+    call /account account-details "session_id=$SESSION_ID"
+    ACCOUNT_ID=$(jq .id assets/account-details.json)
+    call "/account/$ACCOUNT_ID/favorite/movies" account-favorite-movies "session_id=$SESSION_ID"
+    call "/account/$ACCOUNT_ID/favorite/tv" account-favorite-tv "session_id=$SESSION_ID"
+    call "/account/$ACCOUNT_ID/rated/movies" account-rated-movies "session_id=$SESSION_ID"
+    call "/account/$ACCOUNT_ID/rated/tv" account-rated-tv "session_id=$SESSION_ID"
+    call "/account/$ACCOUNT_ID/rated/tv/episodes" account-rated-tv-episodes "session_id=$SESSION_ID"
+    call "/account/$ACCOUNT_ID/watchlist/movies" account-watchlist-movies "session_id=$SESSION_ID"
+    call "/account/$ACCOUNT_ID/watchlist/tv" account-watchlist-tv "session_id=$SESSION_ID"
+    call "/account/$ACCOUNT_ID/lists" account-lists "session_id=$SESSION_ID"
+    call /tv/1399/season/1/account_states tv-season-account-states "session_id=$SESSION_ID"
+    call /tv/1399/season/1/episode/1/account_states tv-episode-account-states "session_id=$SESSION_ID"
+fi
+
+# account v4, with the access token of the user and the object id of its account
+function call_v4() {
+    curl -H "Authorization: Bearer $ACCESS_TOKEN" "https://api.themoviedb.org/4$1?api_key=$API_KEY&$3" | jq . > assets/"$2".json
+}
+
+if [ -n "$ACCESS_TOKEN" ]; then
+    call_v4 "/account/$ACCOUNT_OBJECT_ID/movie/favorites" account-v4-favorite-movies sort_by=release_date.desc
+    call_v4 "/account/$ACCOUNT_OBJECT_ID/tv/favorites" account-v4-favorite-tv sort_by=release_date.desc
+    call_v4 "/account/$ACCOUNT_OBJECT_ID/movie/watchlist" account-v4-watchlist-movies sort_by=release_date.desc
+    call_v4 "/account/$ACCOUNT_OBJECT_ID/tv/watchlist" account-v4-watchlist-tv sort_by=release_date.desc
+    call_v4 "/account/$ACCOUNT_OBJECT_ID/movie/rated" account-v4-rated-movies sort_by=release_date.desc
+    call_v4 "/account/$ACCOUNT_OBJECT_ID/tv/rated" account-v4-rated-tv sort_by=release_date.desc
+fi
+
+# authentication
+call /authentication/guest_session/new authentication-guest-session-new
+call /authentication/token/new authentication-token-new
+
 # certification
 call /certification/movie/list certification-movie-list
 call /certification/tv/list certification-tv-list
 
 # collection
 call /collection/10 collection-details
+call /collection/10/translations collection-translations
 
 # changes
 call /movie/changes movie-all-changes
@@ -28,6 +66,7 @@ call /company/1/alternative_names company-alternative-names
 call /company/1/images company-images
 
 # configuration
+call /configuration configuration-details
 call /configuration/countries configuration-countries
 call /configuration/jobs configuration-jobs
 call /configuration/languages configuration-languages
@@ -35,10 +74,25 @@ call /configuration/languages configuration-languages
 # credits
 call /credit/52542282760ee313280017f9 credit-details
 
+# discover
+call /discover/movie discover-movie "with_genres=28,12&sort_by=popularity.desc"
+call /discover/tv discover-tv "with_genres=18&sort_by=popularity.desc"
+
+# find
+call /find/tt0137523 find-imdb external_source=imdb_id
+call /find/3254641 find-tvdb external_source=tvdb_id
+
 # genres
 call /genre/movie/list genre-movie-list
 call /genre/tv/list genre-tv-list
 
+# keyword
+call /keyword/9715/movies keyword-movies
+
+# list
+call /list/1 list-details
+call /list/1/item_status list-item-status movie_id=550
+
 # movies
 call /movie/550 movie-details
 call /movie/550/alternative_titles movie-alternative-titles
@@ -63,16 +117,41 @@ call /movie/upcoming movie-upcoming
 
 # person
 call /person/287 person-details
+call /person/287/changes person-single-changes "start_date=2024-01-10&end_date=2024-01-20"
+call /person/287/combined_credits person-combined-credits
+call /person/287/translations person-translations
+
+# review
+call /review/5b1c13b9c3a36848f2026384 review-details
 
 # search
 call /search/movie search-movie query=RRRrrrr
+call /search/multi search-multi query=pitt
 call /search/tv search-tv query=game+of+thrones
 
+# trending
+call /trending/all/day trending-all-day
+call /trending/movie/week trending-movie-week
+call /trending/person/day trending-person-day
+call /trending/tv/day trending-tv-day
+
 # tvshows
 call /tv/1399 tv-details
+call /tv/1399/aggregate_credits tv-aggregate-credits
+call /tv/1399/changes tv-single-changes "start_date=2024-01-10&end_date=2024-01-20"
+call /tv/1399/content_ratings tv-content-ratings
+call /tv/1399/credits tv-credits
+call /tv/1399/lists tv-lists
 call /tv/1399/similar tv-similar
+call /tv/1399/translations tv-translations
+call /tv/1399/videos tv-videos include_video_language=en,fr
 call /tv/1399/season/1 tv-season-details
 call /tv/1399/season/1/episode/1 tv-episode-details
+call /tv/airing_today tv-airing-today
+call /tv/episode_group/5b11ba820e0a265847002c6e tv-episode-group-details
+call /tv/episode/63056/changes tv-episode-changes "start_date=2024-01-10&end_date=2024-01-20"
+call /tv/on_the_air tv-on-the-air
+call /tv/season/3624/changes tv-season-changes "start_date=2024-01-10&end_date=2024-01-20"
 
 # watch providers
 call /watch/providers/movie watch-provider-movie-list
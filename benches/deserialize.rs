@@ -0,0 +1,70 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::de::DeserializeOwned;
+use tmdb_api::common::PaginatedResult;
+use tmdb_api::movie::credits::MovieCreditsResult;
+use tmdb_api::movie::{Movie, MovieShort};
+use tmdb_api::tvshow::aggregate_credits::TVShowAggregateCreditsResult;
+use tmdb_api::tvshow::{TVShow, TVShowShort};
+use tmdb_api::watch_provider::list::WatchProviderListResult;
+
+fn bench_payload<T: DeserializeOwned>(c: &mut Criterion, name: &str, payload: &str) {
+    c.bench_function(name, |b| {
+        b.iter(|| serde_json::from_str::<T>(black_box(payload)).unwrap())
+    });
+}
+
+fn pages(c: &mut Criterion) {
+    bench_payload::<PaginatedResult<MovieShort>>(
+        c,
+        "movie popular page",
+        include_str!("../assets/movie-popular.json"),
+    );
+    bench_payload::<PaginatedResult<TVShowShort>>(
+        c,
+        "tvshow popular page",
+        include_str!("../assets/tv-popular.json"),
+    );
+}
+
+fn details(c: &mut Criterion) {
+    bench_payload::<Movie>(
+        c,
+        "movie details",
+        include_str!("../assets/movie-details.json"),
+    );
+    bench_payload::<TVShow>(
+        c,
+        "tvshow details",
+        include_str!("../assets/tv-details-complex.json"),
+    );
+}
+
+fn credits(c: &mut Criterion) {
+    bench_payload::<MovieCreditsResult>(
+        c,
+        "movie credits",
+        include_str!("../assets/movie-credits.json"),
+    );
+    bench_payload::<TVShowAggregateCreditsResult>(
+        c,
+        "tvshow aggregate credits",
+        include_str!("../assets/tv-aggregate-credits.json"),
+    );
+}
+
+fn large_lists(c: &mut Criterion) {
+    #[derive(serde::Deserialize)]
+    struct Wrapper {
+        #[allow(dead_code)]
+        results: Vec<WatchProviderListResult>,
+    }
+
+    bench_payload::<Wrapper>(
+        c,
+        "watch provider list",
+        include_str!("../assets/watch-provider-movie-list.json"),
+    );
+}
+
+criterion_group!(benches, pages, details, credits, large_lists);
+criterion_main!(benches);
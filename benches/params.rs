@@ -0,0 +1,49 @@
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tmdb_api::changes::list::ChangeList;
+use tmdb_api::movie::details::MovieDetails;
+use tmdb_api::movie::search::MovieSearch;
+use tmdb_api::prelude::Command;
+
+fn build_url<C: Command>(command: &C) -> String {
+    let mut url = String::with_capacity(128);
+    url.push_str("https://api.themoviedb.org/3");
+    command.write_path(&mut url);
+    url.push_str("?api_key=secret");
+    for (key, value) in command.params() {
+        url.push('&');
+        url.push_str(key);
+        url.push('=');
+        url.push_str(&value);
+    }
+    url
+}
+
+fn params(c: &mut Criterion) {
+    let details = MovieDetails::new(550).with_language(Some("en-US".into()));
+    c.bench_function("movie details url", |b| {
+        b.iter(|| build_url(black_box(&details)))
+    });
+
+    let search = MovieSearch::new("fight club".into())
+        .with_language(Some("en-US".into()))
+        .with_page(Some(2))
+        .with_include_adult(true)
+        .with_region(Some("US".into()))
+        .with_year(Some(1999))
+        .with_primary_release_year(Some(1999));
+    c.bench_function("movie search url", |b| {
+        b.iter(|| build_url(black_box(&search)))
+    });
+
+    let changes = ChangeList::movie()
+        .with_start_date(NaiveDate::from_ymd_opt(2024, 1, 1))
+        .with_end_date(NaiveDate::from_ymd_opt(2024, 1, 14))
+        .with_page(Some(3));
+    c.bench_function("change list url", |b| {
+        b.iter(|| build_url(black_box(&changes)))
+    });
+}
+
+criterion_group!(benches, params);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tmdb_api::common::PaginatedResult;
+use tmdb_api::movie::MovieShort;
+use tmdb_api::tvshow::TVShowShort;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = serde_json::from_slice::<PaginatedResult<MovieShort>>(data);
+    let _ = serde_json::from_slice::<PaginatedResult<TVShowShort>>(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tmdb_api::tvshow::TVShow;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = serde_json::from_slice::<TVShow>(data);
+});
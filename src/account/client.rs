@@ -0,0 +1,335 @@
+use crate::client::{Client, Executor};
+use crate::common::{MediaType, PaginatedResult, StatusResult};
+use crate::error::Error;
+use crate::movie::MovieShort;
+use crate::prelude::Command;
+use crate::tvshow::TVShowShort;
+
+use super::details::AccountDetails;
+use super::favorite::AccountFavorite;
+use super::favorite_movies::AccountFavoriteMovies;
+use super::favorite_tvshows::AccountFavoriteTVShows;
+use super::lists::AccountLists;
+use super::rated_movies::AccountRatedMovies;
+use super::rated_tvepisodes::AccountRatedTVEpisodes;
+use super::rated_tvshows::AccountRatedTVShows;
+use super::watchlist::AccountWatchlist;
+use super::watchlist_movies::AccountWatchlistMovies;
+use super::watchlist_tvshows::AccountWatchlistTVShows;
+use super::{Account, AccountList, RatedMovie, RatedTVEpisode, RatedTVShow, SortBy};
+
+/// Options of the paginated lists of the account, like its favorites, its
+/// watchlist or its ratings.
+///
+/// ```rust
+/// use tmdb_api::account::client::ListOptions;
+/// use tmdb_api::account::SortBy;
+///
+/// let options = ListOptions::default()
+///     .with_language(Some("fr-FR".into()))
+///     .with_page(Some(2))
+///     .with_sort_by(Some(SortBy::CreatedAtDesc));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListOptions {
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Which page to query.
+    pub page: Option<u32>,
+    /// Sort the results.
+    pub sort_by: Option<SortBy>,
+}
+
+impl ListOptions {
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+/// Client bound to an account and its session, so that the account endpoints
+/// can be called without repeating the `account_id` and `session_id`.
+///
+/// ```rust
+/// use tmdb_api::account::client::AccountClient;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let account = AccountClient::from_session(&client, "this-is-my-session-id".into()).await;
+///     match account {
+///         Ok(account) => println!("favorites: {:#?}", account.favorite_movies(Default::default()).await),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+pub struct AccountClient<'a, E> {
+    client: &'a Client<E>,
+    account_id: u64,
+    session_id: String,
+}
+
+impl<'a, E: Executor> AccountClient<'a, E> {
+    pub fn new(client: &'a Client<E>, account_id: u64, session_id: String) -> Self {
+        Self {
+            client,
+            account_id,
+            session_id,
+        }
+    }
+
+    /// Creates the account client from already fetched account details.
+    pub fn from_account(client: &'a Client<E>, account: &Account, session_id: String) -> Self {
+        Self::new(client, account.id, session_id)
+    }
+
+    /// Fetches the details of the account owning the session and binds the client to it.
+    pub async fn from_session(client: &'a Client<E>, session_id: String) -> Result<Self, Error> {
+        let account = AccountDetails::new(session_id.clone())
+            .execute(client)
+            .await?;
+        Ok(Self::from_account(client, &account, session_id))
+    }
+
+    pub fn client(&self) -> &'a Client<E> {
+        self.client
+    }
+
+    pub fn account_id(&self) -> u64 {
+        self.account_id
+    }
+
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub async fn details(&self) -> Result<Account, Error> {
+        AccountDetails::new(self.session_id.clone())
+            .execute(self.client)
+            .await
+    }
+
+    /// Adds the movie or TV show to the favorites of the account, or removes it.
+    pub async fn set_favorite(
+        &self,
+        media_type: MediaType,
+        media_id: u64,
+        favorite: bool,
+    ) -> Result<StatusResult, Error> {
+        AccountFavorite::new(
+            self.account_id,
+            self.session_id.clone(),
+            media_type,
+            media_id,
+            favorite,
+        )
+        .execute(self.client)
+        .await
+    }
+
+    pub async fn favorite_movies(
+        &self,
+        options: ListOptions,
+    ) -> Result<PaginatedResult<MovieShort>, Error> {
+        AccountFavoriteMovies::new(self.account_id, self.session_id.clone())
+            .with_language(options.language)
+            .with_page(options.page)
+            .with_sort_by(options.sort_by)
+            .execute(self.client)
+            .await
+    }
+
+    pub async fn favorite_tvshows(
+        &self,
+        options: ListOptions,
+    ) -> Result<PaginatedResult<TVShowShort>, Error> {
+        AccountFavoriteTVShows::new(self.account_id, self.session_id.clone())
+            .with_language(options.language)
+            .with_page(options.page)
+            .with_sort_by(options.sort_by)
+            .execute(self.client)
+            .await
+    }
+
+    pub async fn rated_movies(
+        &self,
+        options: ListOptions,
+    ) -> Result<PaginatedResult<RatedMovie>, Error> {
+        AccountRatedMovies::new(self.account_id, self.session_id.clone())
+            .with_language(options.language)
+            .with_page(options.page)
+            .with_sort_by(options.sort_by)
+            .execute(self.client)
+            .await
+    }
+
+    pub async fn rated_tvshows(
+        &self,
+        options: ListOptions,
+    ) -> Result<PaginatedResult<RatedTVShow>, Error> {
+        AccountRatedTVShows::new(self.account_id, self.session_id.clone())
+            .with_language(options.language)
+            .with_page(options.page)
+            .with_sort_by(options.sort_by)
+            .execute(self.client)
+            .await
+    }
+
+    pub async fn rated_tvepisodes(
+        &self,
+        options: ListOptions,
+    ) -> Result<PaginatedResult<RatedTVEpisode>, Error> {
+        AccountRatedTVEpisodes::new(self.account_id, self.session_id.clone())
+            .with_language(options.language)
+            .with_page(options.page)
+            .with_sort_by(options.sort_by)
+            .execute(self.client)
+            .await
+    }
+
+    /// Adds the movie or TV show to the watchlist of the account, or removes it.
+    pub async fn set_watchlist(
+        &self,
+        media_type: MediaType,
+        media_id: u64,
+        watchlist: bool,
+    ) -> Result<StatusResult, Error> {
+        AccountWatchlist::new(
+            self.account_id,
+            self.session_id.clone(),
+            media_type,
+            media_id,
+            watchlist,
+        )
+        .execute(self.client)
+        .await
+    }
+
+    pub async fn watchlist_movies(
+        &self,
+        options: ListOptions,
+    ) -> Result<PaginatedResult<MovieShort>, Error> {
+        AccountWatchlistMovies::new(self.account_id, self.session_id.clone())
+            .with_language(options.language)
+            .with_page(options.page)
+            .with_sort_by(options.sort_by)
+            .execute(self.client)
+            .await
+    }
+
+    pub async fn watchlist_tvshows(
+        &self,
+        options: ListOptions,
+    ) -> Result<PaginatedResult<TVShowShort>, Error> {
+        AccountWatchlistTVShows::new(self.account_id, self.session_id.clone())
+            .with_language(options.language)
+            .with_page(options.page)
+            .with_sort_by(options.sort_by)
+            .execute(self.client)
+            .await
+    }
+
+    pub async fn lists(&self, page: Option<u32>) -> Result<PaginatedResult<AccountList>, Error> {
+        AccountLists::new(self.account_id, self.session_id.clone())
+            .with_page(page)
+            .execute(self.client)
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::account::SortBy;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+
+    use super::{AccountClient, ListOptions};
+
+    #[tokio::test]
+    async fn from_session_binds_account() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _details = server
+            .mock("GET", "/account")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-details.json"))
+            .create_async()
+            .await;
+        let _lists = server
+            .mock("GET", "/account/548/lists")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-lists.json"))
+            .create_async()
+            .await;
+
+        let account = AccountClient::from_session(&client, "session".into())
+            .await
+            .unwrap();
+        assert_eq!(account.account_id(), 548);
+        assert_eq!(account.session_id(), "session");
+
+        let result = account.lists(Some(2)).await.unwrap();
+        assert_eq!(result.results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn favorite_movies_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/favorite/movies")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+                Matcher::UrlEncoded("language".into(), "fr-FR".into()),
+                Matcher::UrlEncoded("sort_by".into(), "created_at.desc".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-favorite-movies.json"))
+            .create_async()
+            .await;
+
+        let account = AccountClient::new(&client, 548, "session".into());
+        let options = ListOptions::default()
+            .with_language(Some("fr-FR".into()))
+            .with_sort_by(Some(SortBy::CreatedAtDesc));
+        let result = account.favorite_movies(options).await.unwrap();
+        assert_eq!(result.page, 1);
+    }
+}
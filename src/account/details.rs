@@ -0,0 +1,170 @@
+use std::borrow::Cow;
+
+const PATH: &str = "/account";
+
+/// Command to get the details of the account owning a session
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::details::AccountDetails;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountDetails::new("this-is-my-session-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AccountDetails {
+    /// ID of the user session.
+    pub session_id: String,
+}
+
+impl AccountDetails {
+    pub fn new(session_id: String) -> Self {
+        Self { session_id }
+    }
+}
+
+impl crate::prelude::Command for AccountDetails {
+    type Output = super::Account;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        vec![("session_id", Cow::Borrowed(self.session_id.as_str()))]
+    }
+}
+
+impl crate::authentication::client::UserSessionCommand for AccountDetails {
+    fn set_session_id(&mut self, value: String) {
+        self.session_id = value;
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountDetails;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-details.json"))
+            .create_async()
+            .await;
+
+        let result = AccountDetails::new("session".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 548);
+        assert_eq!(result.username, "travisbell");
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountDetails::new("session".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = AccountDetails::new("session".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountDetails;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let session_id = std::env::var("TMDB_SESSION_ID").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = AccountDetails::new(session_id)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(!result.username.is_empty());
+    }
+}
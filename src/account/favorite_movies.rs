@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::PaginatedResult;
+use crate::movie::MovieShort;
+
+use super::SortBy;
+
+const PATH: &str = "/account/{account_id}/favorite/movies";
+
+/// Get the list of the favorite movies of an account.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::favorite_movies::AccountFavoriteMovies;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountFavoriteMovies::new(1, "this-is-my-session-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AccountFavoriteMovies {
+    /// ID of the account.
+    pub account_id: u64,
+    /// ID of the user session.
+    pub session_id: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+    /// Sort the results by creation date.
+    pub sort_by: Option<SortBy>,
+}
+
+impl AccountFavoriteMovies {
+    pub fn new(account_id: u64, session_id: String) -> Self {
+        Self {
+            account_id,
+            session_id,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for AccountFavoriteMovies {
+    type Output = PaginatedResult<MovieShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/account/{}/favorite/movies", self.account_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = vec![("session_id", Cow::Borrowed(self.session_id.as_str()))];
+        if let Some(language) = self.language.as_ref() {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        if let Some(sort_by) = self.sort_by {
+            res.push(("sort_by", Cow::Borrowed(sort_by.as_str())));
+        }
+        res
+    }
+}
+
+impl crate::authentication::client::UserSessionCommand for AccountFavoriteMovies {
+    fn set_session_id(&mut self, value: String) {
+        self.session_id = value;
+    }
+}
+
+impl crate::pagination::PaginatedCommand for AccountFavoriteMovies {
+    type Item = MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::account::SortBy;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountFavoriteMovies;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/favorite/movies")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+                Matcher::UrlEncoded("sort_by".into(), "created_at.desc".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-favorite-movies.json"))
+            .create_async()
+            .await;
+
+        let result = AccountFavoriteMovies::new(548, "session".into())
+            .with_sort_by(Some(SortBy::CreatedAtDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert!(!result.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/favorite/movies")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountFavoriteMovies::new(548, "session".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/favorite/movies")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = AccountFavoriteMovies::new(548, "session".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::account::details::AccountDetails;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountFavoriteMovies;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let session_id = std::env::var("TMDB_SESSION_ID").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let account = AccountDetails::new(session_id.clone())
+            .execute(&client)
+            .await
+            .unwrap();
+        let result = AccountFavoriteMovies::new(account.id, session_id)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+    }
+}
@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::PaginatedResult;
+
+use super::AccountList;
+
+const PATH: &str = "/account/{account_id}/lists";
+
+/// Get the lists created by an account.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::lists::AccountLists;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountLists::new(1, "this-is-my-session-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AccountLists {
+    /// ID of the account.
+    pub account_id: u64,
+    /// ID of the user session.
+    pub session_id: String,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+}
+
+impl AccountLists {
+    pub fn new(account_id: u64, session_id: String) -> Self {
+        Self {
+            account_id,
+            session_id,
+            page: None,
+        }
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for AccountLists {
+    type Output = PaginatedResult<AccountList>;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/account/{}/lists", self.account_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = vec![("session_id", Cow::Borrowed(self.session_id.as_str()))];
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        res
+    }
+}
+
+impl crate::authentication::client::UserSessionCommand for AccountLists {
+    fn set_session_id(&mut self, value: String) {
+        self.session_id = value;
+    }
+}
+
+impl crate::pagination::PaginatedCommand for AccountLists {
+    type Item = AccountList;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountLists;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/lists")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-lists.json"))
+            .create_async()
+            .await;
+
+        let result = AccountLists::new(548, "session".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert_eq!(result.results.len(), 2);
+        assert!(result.results[1].description.is_none());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/lists")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountLists::new(548, "session".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/lists")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = AccountLists::new(548, "session".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::account::details::AccountDetails;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountLists;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let session_id = std::env::var("TMDB_SESSION_ID").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let account = AccountDetails::new(session_id.clone())
+            .execute(&client)
+            .await
+            .unwrap();
+        let result = AccountLists::new(account.id, session_id)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(feature = "commands")]
+pub mod client;
+#[cfg(feature = "commands")]
+pub mod details;
+#[cfg(feature = "commands")]
+pub mod favorite;
+#[cfg(feature = "commands")]
+pub mod favorite_movies;
+#[cfg(feature = "commands")]
+pub mod favorite_tvshows;
+#[cfg(feature = "commands")]
+pub mod lists;
+#[cfg(feature = "commands")]
+pub mod rated_movies;
+#[cfg(feature = "commands")]
+pub mod rated_tvepisodes;
+#[cfg(feature = "commands")]
+pub mod rated_tvshows;
+pub mod v4;
+#[cfg(feature = "commands")]
+pub mod watchlist;
+#[cfg(feature = "commands")]
+pub mod watchlist_movies;
+#[cfg(feature = "commands")]
+pub mod watchlist_tvshows;
+
+use crate::movie::MovieShort;
+use crate::tvshow::{EpisodeShort, TVShowShort};
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Gravatar {
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TmdbAvatar {
+    pub avatar_path: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Avatar {
+    pub gravatar: Gravatar,
+    pub tmdb: TmdbAvatar,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Account {
+    pub id: u64,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub name: Option<String>,
+    pub username: String,
+    pub iso_639_1: String,
+    pub iso_3166_1: String,
+    pub include_adult: bool,
+    pub avatar: Avatar,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedMovie {
+    #[serde(flatten)]
+    pub inner: MovieShort,
+    pub rating: f64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields<MovieShort>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedTVShow {
+    #[serde(flatten)]
+    pub inner: TVShowShort,
+    pub rating: f64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields<TVShowShort>,
+}
+
+/// Rated episode, the `show_id` of the episode is always provided.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedTVEpisode {
+    #[serde(flatten)]
+    pub inner: EpisodeShort,
+    pub rating: f64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AccountList {
+    pub id: u64,
+    pub name: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub list_type: String,
+    pub poster_path: Option<String>,
+    pub iso_639_1: String,
+    pub item_count: u64,
+    pub favorite_count: u64,
+}
+
+/// Sort order of the items in the account lists (favorites, watchlist, rated).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    #[default]
+    CreatedAtAsc,
+    CreatedAtDesc,
+}
+
+impl SortBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreatedAtAsc => "created_at.asc",
+            Self::CreatedAtDesc => "created_at.desc",
+        }
+    }
+}
+
+impl std::fmt::Display for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,328 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::PaginatedResult;
+use crate::movie::MovieShort;
+use crate::prelude::Command;
+use crate::tvshow::TVShowShort;
+
+use super::{params, SortBy};
+
+const MOVIE_PATH: &str = "/4/account/{account_id}/movie/favorites";
+const TV_PATH: &str = "/4/account/{account_id}/tv/favorites";
+
+/// Get the list of the favorite movies of an account, with the access token of the user.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::v4::favorites::AccountFavoriteMovies;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountFavoriteMovies::new("this-is-my-access-token".into(), "this-is-my-account-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct AccountFavoriteMovies {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// Object ID of the account, given with the access token.
+    pub account_id: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+    pub sort_by: Option<SortBy>,
+}
+
+impl AccountFavoriteMovies {
+    pub fn new(access_token: String, account_id: String) -> Self {
+        Self {
+            access_token,
+            account_id,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for AccountFavoriteMovies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountFavoriteMovies")
+            .field("access_token", &"redacted")
+            .field("account_id", &self.account_id)
+            .field("language", &self.language)
+            .field("page", &self.page)
+            .field("sort_by", &self.sort_by)
+            .finish()
+    }
+}
+
+impl Command for AccountFavoriteMovies {
+    type Output = PaginatedResult<MovieShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        MOVIE_PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/4/account/{}/movie/favorites", self.account_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        params(self.language.as_deref(), self.page, self.sort_by)
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+impl crate::pagination::PaginatedCommand for AccountFavoriteMovies {
+    type Item = MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+/// Get the list of the favorite TV shows of an account, with the access token of the user.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::v4::favorites::AccountFavoriteTVShows;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountFavoriteTVShows::new("this-is-my-access-token".into(), "this-is-my-account-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct AccountFavoriteTVShows {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// Object ID of the account, given with the access token.
+    pub account_id: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+    pub sort_by: Option<SortBy>,
+}
+
+impl AccountFavoriteTVShows {
+    pub fn new(access_token: String, account_id: String) -> Self {
+        Self {
+            access_token,
+            account_id,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for AccountFavoriteTVShows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountFavoriteTVShows")
+            .field("access_token", &"redacted")
+            .field("account_id", &self.account_id)
+            .field("language", &self.language)
+            .field("page", &self.page)
+            .field("sort_by", &self.sort_by)
+            .finish()
+    }
+}
+
+impl Command for AccountFavoriteTVShows {
+    type Output = PaginatedResult<TVShowShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        TV_PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/4/account/{}/tv/favorites", self.account_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        params(self.language.as_deref(), self.page, self.sort_by)
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+impl crate::pagination::PaginatedCommand for AccountFavoriteTVShows {
+    type Item = TVShowShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::account::v4::SortBy;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::{AccountFavoriteMovies, AccountFavoriteTVShows};
+
+    const ACCOUNT_ID: &str = "4bc889XXXXXXXXXXXXXXXXXX";
+
+    #[tokio::test]
+    async fn favorite_movies_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/account/4bc889XXXXXXXXXXXXXXXXXX/movie/favorites")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("sort_by".into(), "release_date.desc".into()),
+            ]))
+            .match_header("authorization", "Bearer token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../../assets/account-v4-favorite-movies.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = AccountFavoriteMovies::new("token".into(), ACCOUNT_ID.into())
+            .with_sort_by(Some(SortBy::ReleaseDateDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert!(!result.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn favorite_tvshows_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/account/4bc889XXXXXXXXXXXXXXXXXX/tv/favorites")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("sort_by".into(), "release_date.desc".into()),
+            ]))
+            .match_header("authorization", "Bearer token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/account-v4-favorite-tv.json"))
+            .create_async()
+            .await;
+
+        let result = AccountFavoriteTVShows::new("token".into(), ACCOUNT_ID.into())
+            .with_sort_by(Some(SortBy::ReleaseDateDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert!(!result.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/account/4bc889XXXXXXXXXXXXXXXXXX/movie/favorites")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountFavoriteMovies::new("token".into(), ACCOUNT_ID.into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
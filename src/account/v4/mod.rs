@@ -0,0 +1,98 @@
+//! Account lists with the v4 API, authenticated with the access token of the
+//! user and identified by the object ID of the account.
+
+#[cfg(feature = "commands")]
+pub mod favorites;
+#[cfg(feature = "commands")]
+pub mod rated;
+#[cfg(feature = "commands")]
+pub mod watchlist;
+
+#[cfg(feature = "commands")]
+use std::borrow::Cow;
+
+use crate::movie::MovieShort;
+use crate::tvshow::TVShowShort;
+
+/// Sort order of the items in the v4 account lists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    CreatedAtAsc,
+    CreatedAtDesc,
+    ReleaseDateAsc,
+    ReleaseDateDesc,
+    TitleAsc,
+    TitleDesc,
+    VoteAverageAsc,
+    VoteAverageDesc,
+}
+
+impl SortBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreatedAtAsc => "created_at.asc",
+            Self::CreatedAtDesc => "created_at.desc",
+            Self::ReleaseDateAsc => "release_date.asc",
+            Self::ReleaseDateDesc => "release_date.desc",
+            Self::TitleAsc => "title.asc",
+            Self::TitleDesc => "title.desc",
+            Self::VoteAverageAsc => "vote_average.asc",
+            Self::VoteAverageDesc => "vote_average.desc",
+        }
+    }
+}
+
+impl std::fmt::Display for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Rating given by the user, with the time it was given.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AccountRating {
+    pub value: f64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedMovie {
+    #[serde(flatten)]
+    pub inner: MovieShort,
+    pub account_rating: AccountRating,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields<MovieShort>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedTVShow {
+    #[serde(flatten)]
+    pub inner: TVShowShort,
+    pub account_rating: AccountRating,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields<TVShowShort>,
+}
+
+#[cfg(feature = "commands")]
+fn params(
+    language: Option<&str>,
+    page: Option<u32>,
+    sort_by: Option<SortBy>,
+) -> Vec<(&'static str, Cow<'_, str>)> {
+    let mut res = Vec::new();
+    if let Some(language) = language {
+        res.push(("language", Cow::Borrowed(language)));
+    }
+    if let Some(page) = page {
+        res.push(("page", Cow::Owned(page.to_string())));
+    }
+    if let Some(sort_by) = sort_by {
+        res.push(("sort_by", Cow::Borrowed(sort_by.as_str())));
+    }
+    res
+}
@@ -0,0 +1,328 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::PaginatedResult;
+use crate::movie::MovieShort;
+use crate::prelude::Command;
+use crate::tvshow::TVShowShort;
+
+use super::{params, SortBy};
+
+const MOVIE_PATH: &str = "/4/account/{account_id}/movie/watchlist";
+const TV_PATH: &str = "/4/account/{account_id}/tv/watchlist";
+
+/// Get the list of the movies in the watchlist of an account, with the access token of the user.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::v4::watchlist::AccountWatchlistMovies;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountWatchlistMovies::new("this-is-my-access-token".into(), "this-is-my-account-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct AccountWatchlistMovies {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// Object ID of the account, given with the access token.
+    pub account_id: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+    pub sort_by: Option<SortBy>,
+}
+
+impl AccountWatchlistMovies {
+    pub fn new(access_token: String, account_id: String) -> Self {
+        Self {
+            access_token,
+            account_id,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for AccountWatchlistMovies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountWatchlistMovies")
+            .field("access_token", &"redacted")
+            .field("account_id", &self.account_id)
+            .field("language", &self.language)
+            .field("page", &self.page)
+            .field("sort_by", &self.sort_by)
+            .finish()
+    }
+}
+
+impl Command for AccountWatchlistMovies {
+    type Output = PaginatedResult<MovieShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        MOVIE_PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/4/account/{}/movie/watchlist", self.account_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        params(self.language.as_deref(), self.page, self.sort_by)
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+impl crate::pagination::PaginatedCommand for AccountWatchlistMovies {
+    type Item = MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+/// Get the list of the TV shows in the watchlist of an account, with the access token of the user.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::v4::watchlist::AccountWatchlistTVShows;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountWatchlistTVShows::new("this-is-my-access-token".into(), "this-is-my-account-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct AccountWatchlistTVShows {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// Object ID of the account, given with the access token.
+    pub account_id: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+    pub sort_by: Option<SortBy>,
+}
+
+impl AccountWatchlistTVShows {
+    pub fn new(access_token: String, account_id: String) -> Self {
+        Self {
+            access_token,
+            account_id,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for AccountWatchlistTVShows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountWatchlistTVShows")
+            .field("access_token", &"redacted")
+            .field("account_id", &self.account_id)
+            .field("language", &self.language)
+            .field("page", &self.page)
+            .field("sort_by", &self.sort_by)
+            .finish()
+    }
+}
+
+impl Command for AccountWatchlistTVShows {
+    type Output = PaginatedResult<TVShowShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        TV_PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/4/account/{}/tv/watchlist", self.account_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        params(self.language.as_deref(), self.page, self.sort_by)
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+impl crate::pagination::PaginatedCommand for AccountWatchlistTVShows {
+    type Item = TVShowShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::account::v4::SortBy;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::{AccountWatchlistMovies, AccountWatchlistTVShows};
+
+    const ACCOUNT_ID: &str = "4bc889XXXXXXXXXXXXXXXXXX";
+
+    #[tokio::test]
+    async fn watchlist_movies_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/account/4bc889XXXXXXXXXXXXXXXXXX/movie/watchlist")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("sort_by".into(), "release_date.desc".into()),
+            ]))
+            .match_header("authorization", "Bearer token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../../assets/account-v4-watchlist-movies.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = AccountWatchlistMovies::new("token".into(), ACCOUNT_ID.into())
+            .with_sort_by(Some(SortBy::ReleaseDateDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert!(!result.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn watchlist_tvshows_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/account/4bc889XXXXXXXXXXXXXXXXXX/tv/watchlist")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("sort_by".into(), "release_date.desc".into()),
+            ]))
+            .match_header("authorization", "Bearer token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/account-v4-watchlist-tv.json"))
+            .create_async()
+            .await;
+
+        let result = AccountWatchlistTVShows::new("token".into(), ACCOUNT_ID.into())
+            .with_sort_by(Some(SortBy::ReleaseDateDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert!(!result.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/account/4bc889XXXXXXXXXXXXXXXXXX/movie/watchlist")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountWatchlistMovies::new("token".into(), ACCOUNT_ID.into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,202 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::authentication::client::UserSessionCommand;
+use crate::client::Method;
+use crate::common::{MediaType, StatusResult};
+use crate::prelude::Command;
+
+const PATH: &str = "/account/{account_id}/watchlist";
+
+/// Command to add or remove a movie or a TV show from the watchlist of an
+/// account.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::watchlist::AccountWatchlist;
+/// use tmdb_api::common::MediaType;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = AccountWatchlist::new(1, "this-is-my-session-id".into(), MediaType::Movie, 550, true);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AccountWatchlist {
+    /// ID of the account.
+    pub account_id: u64,
+    /// ID of the user session.
+    pub session_id: String,
+    /// Type of the media, only `movie` and `tv` are supported.
+    pub media_type: MediaType,
+    /// ID of the movie or TV show.
+    pub media_id: u64,
+    /// Whether the media should be added to, or removed from, the watchlist.
+    pub watchlist: bool,
+}
+
+impl AccountWatchlist {
+    pub fn new(
+        account_id: u64,
+        session_id: String,
+        media_type: MediaType,
+        media_id: u64,
+        watchlist: bool,
+    ) -> Self {
+        Self {
+            account_id,
+            session_id,
+            media_type,
+            media_id,
+            watchlist,
+        }
+    }
+}
+
+impl Command for AccountWatchlist {
+    type Output = StatusResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/account/{}/watchlist", self.account_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        vec![("session_id", Cow::Borrowed(self.session_id.as_str()))]
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "media_type": self.media_type,
+            "media_id": self.media_id,
+            "watchlist": self.watchlist,
+        }))
+    }
+}
+
+impl UserSessionCommand for AccountWatchlist {
+    fn set_session_id(&mut self, value: String) {
+        self.session_id = value;
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::MediaType;
+    use crate::prelude::Command;
+
+    use super::AccountWatchlist;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", "/account/548/watchlist")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(serde_json::json!({
+                "media_type": "movie",
+                "media_id": 550,
+                "watchlist": true,
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-watchlist.json"))
+            .create_async()
+            .await;
+
+        let result = AccountWatchlist::new(548, "session".into(), MediaType::Movie, 550, true)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, 1);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/account/548/watchlist")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountWatchlist::new(548, "session".into(), MediaType::Tv, 1399, false)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/account/548/watchlist")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = AccountWatchlist::new(548, "session".into(), MediaType::Tv, 1399, false)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::client::{Client, Executor};
+use crate::error::Error;
+use crate::prelude::Command;
+
+/// Session identifying the user of the commands, created with
+/// [super::session::CreateSession] or [super::guest_session::CreateGuestSession].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionId {
+    User(String),
+    Guest(String),
+}
+
+/// Command that can be executed with a user or a guest session, like the
+/// rating and account states commands.
+pub trait SessionCommand: Command + Sync {
+    fn set_session_id(&mut self, value: Option<String>);
+    fn set_guest_session_id(&mut self, value: Option<String>);
+
+    /// Sets the given session, replacing any session already set.
+    fn set_session(&mut self, session: &SessionId) {
+        match session {
+            SessionId::User(value) => {
+                self.set_session_id(Some(value.clone()));
+                self.set_guest_session_id(None);
+            }
+            SessionId::Guest(value) => {
+                self.set_session_id(None);
+                self.set_guest_session_id(Some(value.clone()));
+            }
+        }
+    }
+}
+
+/// Command that can only be executed with a user session, like the account and
+/// list commands, TMDB rejecting the guest sessions.
+pub trait UserSessionCommand: Command + Sync {
+    fn set_session_id(&mut self, value: String);
+}
+
+/// Client bound to a session, so that the session doesn't have to be set on
+/// every command executed on behalf of the user.
+///
+/// ```rust
+/// use tmdb_api::authentication::client::AuthenticatedClient;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::rating::Rating;
+/// use tmdb_api::movie::rating::MovieAddRating;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let client = AuthenticatedClient::guest(&client, "this-is-my-guest-session-id".into());
+///     let cmd = MovieAddRating::new(550, Rating::new(8.5).unwrap());
+///     match client.execute(cmd).await {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+pub struct AuthenticatedClient<'a, E> {
+    client: &'a Client<E>,
+    session: SessionId,
+}
+
+impl<'a, E: Executor + Send + Sync> AuthenticatedClient<'a, E> {
+    pub fn new(client: &'a Client<E>, session: SessionId) -> Self {
+        Self { client, session }
+    }
+
+    pub fn user(client: &'a Client<E>, session_id: String) -> Self {
+        Self::new(client, SessionId::User(session_id))
+    }
+
+    pub fn guest(client: &'a Client<E>, guest_session_id: String) -> Self {
+        Self::new(client, SessionId::Guest(guest_session_id))
+    }
+
+    pub fn client(&self) -> &'a Client<E> {
+        self.client
+    }
+
+    pub fn session(&self) -> &SessionId {
+        &self.session
+    }
+
+    /// Executes the command with the session of the client.
+    pub async fn execute<C: SessionCommand>(&self, mut command: C) -> Result<C::Output, Error> {
+        command.set_session(&self.session);
+        command.execute(self.client).await
+    }
+
+    /// Executes the command with the user session of the client, failing with
+    /// [Error::UserSessionRequired] when bound to a guest session.
+    pub async fn execute_as_user<C: UserSessionCommand>(
+        &self,
+        mut command: C,
+    ) -> Result<C::Output, Error> {
+        match self.session {
+            SessionId::User(ref value) => command.set_session_id(value.clone()),
+            SessionId::Guest(_) => return Err(Error::UserSessionRequired),
+        }
+        command.execute(self.client).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::account::details::AccountDetails;
+    use crate::account::favorite_movies::AccountFavoriteMovies;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::rating::Rating;
+    use crate::list::clear::ListClear;
+    use crate::movie::rating::MovieAddRating;
+    use crate::prelude::Command;
+    use crate::tvshow::episode::account_states::TVShowEpisodeAccountStates;
+
+    use super::{AuthenticatedClient, SessionCommand, SessionId};
+
+    #[test]
+    fn should_replace_session() {
+        let mut cmd =
+            TVShowEpisodeAccountStates::new(1399, 1, 1).with_guest_session_id(Some("guest".into()));
+        cmd.set_session(&SessionId::User("session".into()));
+        assert_eq!(cmd.params(), vec![("session_id", "session".into())]);
+        cmd.set_session(&SessionId::Guest("guest".into()));
+        assert_eq!(cmd.params(), vec![("guest_session_id", "guest".into())]);
+    }
+
+    #[tokio::test]
+    async fn should_set_user_session() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/account_states")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-episode-account-states.json"))
+            .create_async()
+            .await;
+
+        let client = AuthenticatedClient::user(&client, "session".into());
+        let cmd =
+            TVShowEpisodeAccountStates::new(1399, 1, 1).with_guest_session_id(Some("guest".into()));
+        client.execute(cmd).await.unwrap();
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_set_guest_session() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", "/movie/550/rating")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("guest_session_id".into(), "guest".into()),
+            ]))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-add-rating.json"))
+            .create_async()
+            .await;
+
+        let client = AuthenticatedClient::guest(&client, "guest".into());
+        let result = client
+            .execute(MovieAddRating::new(550, Rating::new(8.5).unwrap()))
+            .await
+            .unwrap();
+        assert!(result.success);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_require_user_session_on_account_commands() {
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url("http://localhost:1")
+            .build()
+            .unwrap();
+        let client = AuthenticatedClient::guest(&client, "guest".into());
+        let err = client
+            .execute_as_user(AccountDetails::default())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, crate::error::Error::UserSessionRequired),
+            "{err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_set_session_on_account_commands() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let details = server
+            .mock("GET", "/account")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-details.json"))
+            .create_async()
+            .await;
+        let favorites = server
+            .mock("GET", "/account/1/favorite/movies")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-favorite-movies.json"))
+            .create_async()
+            .await;
+        let clear = server
+            .mock("POST", "/list/1/clear")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+                Matcher::UrlEncoded("confirm".into(), "true".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/list-clear.json"))
+            .create_async()
+            .await;
+
+        let client = AuthenticatedClient::user(&client, "session".into());
+        client
+            .execute_as_user(AccountDetails::default())
+            .await
+            .unwrap();
+        client
+            .execute_as_user(AccountFavoriteMovies::new(1, String::new()))
+            .await
+            .unwrap();
+        let result = client
+            .execute_as_user(ListClear::new(1, String::new(), true))
+            .await
+            .unwrap();
+        assert!(result.success);
+        details.assert_async().await;
+        favorites.assert_async().await;
+        clear.assert_async().await;
+    }
+}
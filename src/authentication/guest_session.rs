@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+use crate::prelude::Command;
+
+const PATH: &str = "/authentication/guest_session/new";
+
+/// Command to create a guest session, to rate movies and TV shows without
+/// a user account.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::authentication::guest_session::CreateGuestSession;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let result = CreateGuestSession::default().execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CreateGuestSession;
+
+impl Command for CreateGuestSession {
+    type Output = super::GuestSession;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::CreateGuestSession;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/authentication-guest-session-new.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = CreateGuestSession.execute(&client).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.guest_session_id, "1ce82ec1223641636ad4a60b07de3581");
+        assert_eq!(result.expires_at.to_rfc3339(), "2016-08-27T16:26:40+00:00");
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = CreateGuestSession.execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::CreateGuestSession;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = CreateGuestSession.execute(&client).await.unwrap();
+        assert!(result.success);
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(feature = "commands")]
+pub mod client;
+#[cfg(feature = "commands")]
+pub mod guest_session;
+#[cfg(feature = "commands")]
+pub mod request_token;
+#[cfg(feature = "commands")]
+pub mod session;
+#[cfg(feature = "commands")]
+pub mod validate_with_login;
+
+/// Temporary token, to be validated by the user before creating a session.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RequestToken {
+    #[serde(default)]
+    pub success: bool,
+    #[serde(with = "crate::util::utc_datetime")]
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    pub request_token: String,
+}
+
+/// Session of a user, required by the account and rating commands.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Session {
+    #[serde(default)]
+    pub success: bool,
+    pub session_id: String,
+}
+
+/// Anonymous session, only allowed to rate movies, TV shows and episodes.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GuestSession {
+    #[serde(default)]
+    pub success: bool,
+    pub guest_session_id: String,
+    #[serde(with = "crate::util::utc_datetime")]
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,257 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+use crate::prelude::Command;
+
+const PATH: &str = "/authentication/session/new";
+const CONVERT_PATH: &str = "/authentication/session/convert/4";
+
+/// Command to create a session from a request token validated by the user.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::authentication::session::CreateSession;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = CreateSession::new("this-is-my-request-token".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateSession {
+    /// Request token validated by the user.
+    pub request_token: String,
+}
+
+impl CreateSession {
+    pub fn new(request_token: String) -> Self {
+        Self { request_token }
+    }
+}
+
+impl Command for CreateSession {
+    type Output = super::Session;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "request_token": self.request_token }))
+    }
+}
+
+/// Command to create a session from an access token of the v4 authentication,
+/// without asking the user to authenticate again.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::authentication::session::ConvertSession;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ConvertSession::new("this-is-my-access-token".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ConvertSession {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+}
+
+impl ConvertSession {
+    pub fn new(access_token: String) -> Self {
+        Self { access_token }
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for ConvertSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConvertSession")
+            .field("access_token", &"redacted")
+            .finish()
+    }
+}
+
+impl Command for ConvertSession {
+    type Output = super::Session;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(CONVERT_PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        CONVERT_PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "access_token": self.access_token }))
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::{ConvertSession, CreateSession};
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(
+                serde_json::json!({ "request_token": "token" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/authentication-session-new.json"))
+            .create_async()
+            .await;
+
+        let result = CreateSession::new("token".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(
+            result.session_id,
+            "79191836ddaa0da3df76a5ffef6f07ad6ab0c641"
+        );
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn convert_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", super::CONVERT_PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(
+                serde_json::json!({ "access_token": "token" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/authentication-session-new.json"))
+            .create_async()
+            .await;
+
+        let cmd = ConvertSession::new("token".into());
+        assert!(!format!("{cmd:?}").contains("\"token\""));
+        let result = cmd.execute(&client).await.unwrap();
+        assert!(result.success);
+        assert_eq!(
+            result.session_id,
+            "79191836ddaa0da3df76a5ffef6f07ad6ab0c641"
+        );
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = CreateSession::new("token".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = CreateSession::new("token".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+use crate::prelude::Command;
+
+const PATH: &str = "/authentication/token/validate_with_login";
+
+/// Command to validate a request token with the credentials of the user,
+/// for the applications that can't redirect the user to the TMDB website.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::authentication::validate_with_login::ValidateRequestTokenWithLogin;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ValidateRequestTokenWithLogin::new(
+///         "username".into(),
+///         "password".into(),
+///         "this-is-my-request-token".into(),
+///     );
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ValidateRequestTokenWithLogin {
+    pub username: String,
+    pub password: String,
+    /// Request token created with [super::request_token::CreateRequestToken].
+    pub request_token: String,
+}
+
+impl ValidateRequestTokenWithLogin {
+    pub fn new(username: String, password: String, request_token: String) -> Self {
+        Self {
+            username,
+            password,
+            request_token,
+        }
+    }
+}
+
+/// Keeps the password out of the logs.
+impl std::fmt::Debug for ValidateRequestTokenWithLogin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidateRequestTokenWithLogin")
+            .field("username", &self.username)
+            .field("password", &"redacted")
+            .field("request_token", &self.request_token)
+            .finish()
+    }
+}
+
+impl Command for ValidateRequestTokenWithLogin {
+    type Output = super::RequestToken;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "username": self.username,
+            "password": self.password,
+            "request_token": self.request_token,
+        }))
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::ValidateRequestTokenWithLogin;
+
+    fn command() -> ValidateRequestTokenWithLogin {
+        ValidateRequestTokenWithLogin::new("john".into(), "doe".into(), "token".into())
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(serde_json::json!({
+                "username": "john",
+                "password": "doe",
+                "request_token": "token",
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/authentication-token-validate-with-login.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = command().execute(&client).await.unwrap();
+        assert!(result.success);
+        assert_eq!(
+            result.request_token,
+            "1531f1a558c8357ce8990cf887ff196e8f5402ec"
+        );
+        m.assert_async().await;
+    }
+
+    #[test]
+    fn should_not_debug_password() {
+        let output = format!("{:?}", command());
+        assert!(output.contains("john"));
+        assert!(!output.contains("doe"));
+    }
+
+    #[tokio::test]
+    async fn invalid_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-credentials.json"))
+            .create_async()
+            .await;
+
+        let err = command().execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 30);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = command().execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,164 @@
+//! Execute a large amount of requests with a bounded concurrency.
+//!
+//! Every request goes through the [Client], so whatever throttling the client
+//! applies is respected by the scheduled requests as well.
+
+use std::future::Future;
+
+use futures_util::stream::{self, StreamExt};
+
+use crate::client::{Client, Executor};
+use crate::error::Error;
+use crate::prelude::Command;
+
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Scheduler executing requests with a bounded concurrency, returning the results
+/// in the same order as the requests.
+///
+/// ```rust
+/// use tmdb_api::batch::BatchScheduler;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::movie::details::MovieDetails;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let commands = [550, 551, 552].into_iter().map(MovieDetails::new);
+///     let results = BatchScheduler::new(2).execute_all(&client, commands).await;
+///     for result in results {
+///         match result {
+///             Ok(res) => println!("found: {:#?}", res),
+///             Err(err) => eprintln!("error: {:?}", err),
+///         };
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BatchScheduler {
+    concurrency: usize,
+}
+
+impl Default for BatchScheduler {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONCURRENCY)
+    }
+}
+
+impl BatchScheduler {
+    /// Creates a scheduler running at most `concurrency` requests at the same time.
+    pub fn new(concurrency: usize) -> Self {
+        Self {
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Runs the given request closures, returning their results in order.
+    pub async fn run<I, F, Fut, T>(&self, requests: I) -> Vec<Result<T, Error>>
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        stream::iter(requests)
+            .map(|request| request())
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    /// Executes the given commands, returning their results in order.
+    pub async fn execute_all<C, I, E>(
+        &self,
+        client: &Client<E>,
+        commands: I,
+    ) -> Vec<Result<C::Output, Error>>
+    where
+        C: Command + Sync,
+        I: IntoIterator<Item = C>,
+        E: Executor,
+    {
+        self.run(
+            commands
+                .into_iter()
+                .map(|command| move || async move { command.execute(client).await }),
+        )
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::movie::details::MovieDetails;
+
+    use super::BatchScheduler;
+
+    #[tokio::test]
+    async fn run_keeps_order_and_bounds_concurrency() {
+        let running = AtomicUsize::new(0);
+        let max_running = AtomicUsize::new(0);
+
+        let requests = (0..10u64).map(|index| {
+            let running = &running;
+            let max_running = &max_running;
+            move || async move {
+                let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(10 - index)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(index)
+            }
+        });
+
+        let results = BatchScheduler::new(3).run(requests).await;
+        let results: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(results, (0..10).collect::<Vec<_>>());
+        assert!(max_running.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[tokio::test]
+    async fn execute_all_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _found = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../assets/movie-details.json"))
+            .expect(2)
+            .create_async()
+            .await;
+        let _not_found = server
+            .mock("GET", "/movie/1")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let commands = [550, 1, 550].into_iter().map(MovieDetails::new);
+        let results = BatchScheduler::new(2).execute_all(&client, commands).await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().inner.id, 550);
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap().inner.id, 550);
+    }
+}
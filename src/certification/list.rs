@@ -58,6 +58,10 @@ impl crate::prelude::Command for CertificationList {
         Cow::Borrowed(self.path)
     }
 
+    fn path_template(&self) -> &'static str {
+        self.path
+    }
+
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
         Vec::new()
     }
@@ -73,7 +77,7 @@ impl crate::prelude::Command for CertificationList {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -2,6 +2,7 @@
 pub mod list;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Certification {
     pub certification: String,
     pub meaning: String,
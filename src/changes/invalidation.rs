@@ -0,0 +1,421 @@
+//! Keep long lived caches consistent with the changes API.
+//!
+//! Instead of expiring the cached details after some time, the IDs listed by the
+//! changes API since the last synchronization get evicted from the cache.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::BuildHasher;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::NaiveDate;
+
+use crate::client::cache::Cache;
+use crate::client::{Client, Executor};
+
+use super::list::ChangeList;
+use super::window::ChangeScanner;
+
+/// Cache whose entries, keyed by TMDB ID, can be invalidated.
+pub trait Invalidate {
+    /// Evicts, or refreshes, the entries of the given IDs.
+    fn invalidate(&mut self, ids: &[u64]);
+}
+
+impl<V, S: BuildHasher> Invalidate for HashMap<u64, V, S> {
+    fn invalidate(&mut self, ids: &[u64]) {
+        for id in ids {
+            self.remove(id);
+        }
+    }
+}
+
+impl<V> Invalidate for BTreeMap<u64, V> {
+    fn invalidate(&mut self, ids: &[u64]) {
+        for id in ids {
+            self.remove(id);
+        }
+    }
+}
+
+impl<S: BuildHasher> Invalidate for HashSet<u64, S> {
+    fn invalidate(&mut self, ids: &[u64]) {
+        for id in ids {
+            self.remove(id);
+        }
+    }
+}
+
+/// Response [Cache] of a [Client], evicting the responses of the given paths
+/// for the changed IDs.
+///
+/// The paths contain the `{id}` parameter, like `/movie/{id}` or
+/// `/movie/{id}/credits`, and get combined with each of the queries of the
+/// cached requests, as sent and without the api key, like `language=fr-FR`.
+/// Only the requests without query are evicted by default.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use tmdb_api::changes::invalidation::{ChangeFeed, ClientCache};
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// async fn sync(client: &Client<ReqwestExecutor>, feed: &mut ChangeFeed, today: NaiveDate) {
+///     let Some(cache) = ClientCache::new(client, vec!["/movie/{id}", "/movie/{id}/credits"]) else {
+///         return;
+///     };
+///     let mut cache = cache.with_queries(vec![String::new(), "language=fr-FR".into()]);
+///     match feed.sync(client, today, &mut cache).await {
+///         Ok(count) => println!("{count} movies changed"),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+pub struct ClientCache {
+    cache: Arc<dyn Cache>,
+    base_url: String,
+    paths: Vec<&'static str>,
+    queries: Vec<String>,
+}
+
+impl ClientCache {
+    /// Cache of the client, if it has one.
+    pub fn new<E: Executor>(client: &Client<E>, paths: Vec<&'static str>) -> Option<Self> {
+        Some(Self {
+            cache: client.cache()?.clone(),
+            base_url: client.base_url().to_string(),
+            paths,
+            queries: vec![String::new()],
+        })
+    }
+
+    /// Replaces the queries of the cached requests, the empty one standing for
+    /// the requests without query.
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Key of the response of a path, for the given ID and query.
+    fn key(&self, path: &str, id: u64, query: &str) -> String {
+        let mut key = self.base_url.clone();
+        key.push_str(&path.replace("{id}", &id.to_string()));
+        if !query.is_empty() {
+            key.push('?');
+            key.push_str(query);
+        }
+        key
+    }
+}
+
+impl Invalidate for ClientCache {
+    fn invalidate(&mut self, ids: &[u64]) {
+        for id in ids {
+            for path in self.paths.iter() {
+                for query in self.queries.iter() {
+                    self.cache.remove(&self.key(path, *id, query));
+                }
+            }
+        }
+    }
+}
+
+/// Invalidates a cache with the IDs that changed since the last synchronization.
+///
+/// Each synchronization is triggered manually with [ChangeFeed::sync], to be
+/// called periodically with the current date, or by the loop of
+/// [ChangeFeed::run], to be spawned as a background task.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use chrono::NaiveDate;
+/// use tmdb_api::changes::invalidation::ChangeFeed;
+/// use tmdb_api::changes::list::ChangeList;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::movie::Movie;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let mut cache: HashMap<u64, Movie> = HashMap::new();
+///     let since = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+///     let mut feed = ChangeFeed::new(ChangeList::movie(), since);
+///     let today = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+///     match feed.sync(&client, today, &mut cache).await {
+///         Ok(count) => println!("{count} movies changed"),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChangeFeed {
+    command: ChangeList,
+    last_sync: NaiveDate,
+}
+
+impl ChangeFeed {
+    /// Creates a feed listing the changes of the given command, starting at the
+    /// date of the last synchronization of the cache.
+    pub fn new(command: ChangeList, since: NaiveDate) -> Self {
+        Self {
+            command,
+            last_sync: since,
+        }
+    }
+
+    /// Date from which the next synchronization will fetch the changes.
+    pub fn last_sync(&self) -> NaiveDate {
+        self.last_sync
+    }
+
+    /// Fetches the changes from the last synchronization until `today`, both
+    /// included, and invalidates them in the cache page by page. Returns the
+    /// number of changed IDs.
+    ///
+    /// The date of the last synchronization is only updated on success, so a
+    /// failed synchronization is fully retried on the next call.
+    pub async fn sync<E: Executor, C: Invalidate + ?Sized>(
+        &mut self,
+        client: &Client<E>,
+        today: NaiveDate,
+        cache: &mut C,
+    ) -> Result<usize, crate::error::Error> {
+        let mut scanner = ChangeScanner::new(self.command.clone(), self.last_sync, today);
+        let mut count = 0;
+        while let Some(ids) = scanner.next_ids(client).await? {
+            cache.invalidate(&ids);
+            count += ids.len();
+        }
+        // the changes of today are not over yet, so they're fetched again next time
+        self.last_sync = today.max(self.last_sync);
+        Ok(count)
+    }
+
+    /// Synchronizes the cache every `interval`, forever, with the date returned
+    /// by `today`, waiting between the synchronizations with the
+    /// [Executor::sleep] of the client.
+    ///
+    /// It's meant to be spawned on the runtime of the executor. A failed
+    /// synchronization is logged and retried on the next iteration.
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    /// use chrono::{DateTime, NaiveDate};
+    /// use tmdb_api::changes::invalidation::ChangeFeed;
+    /// use tmdb_api::changes::list::ChangeList;
+    /// use tmdb_api::client::Client;
+    /// use tmdb_api::client::reqwest::ReqwestExecutor;
+    /// use tmdb_api::movie::Movie;
+    ///
+    /// fn today() -> NaiveDate {
+    ///     let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    ///     DateTime::from_timestamp(elapsed.as_secs() as i64, 0).unwrap().date_naive()
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+    ///     let mut feed = ChangeFeed::new(ChangeList::movie(), today());
+    ///     tokio::spawn(async move {
+    ///         let mut cache: HashMap<u64, Movie> = HashMap::new();
+    ///         feed.run(&client, &mut cache, Duration::from_secs(3600), today)
+    ///             .await
+    ///     });
+    /// }
+    /// ```
+    pub async fn run<E, C, F>(
+        &mut self,
+        client: &Client<E>,
+        cache: &mut C,
+        interval: Duration,
+        mut today: F,
+    ) where
+        E: Executor,
+        C: Invalidate + ?Sized,
+        F: FnMut() -> NaiveDate,
+    {
+        loop {
+            if let Err(err) = self.sync(client, today(), cache).await {
+                log::warn!("couldn't synchronize the changes, retrying later: {err}");
+            }
+            client.executor().sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::NaiveDate;
+    use mockito::Matcher;
+
+    use crate::changes::list::ChangeList;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+
+    use super::ChangeFeed;
+
+    #[tokio::test]
+    async fn should_invalidate_changed_ids() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/changes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("start_date".into(), "2024-01-01".into()),
+                Matcher::UrlEncoded("end_date".into(), "2024-01-05".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"page":1,"total_pages":1,"total_results":3,"results":[{"id":1,"adult":false},{"id":3,"adult":false},{"id":5,"adult":false}]}"#)
+            .create_async()
+            .await;
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let today = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let mut cache: HashMap<u64, &str> =
+            HashMap::from_iter([(1, "one"), (2, "two"), (3, "three"), (4, "four")]);
+        let mut feed = ChangeFeed::new(ChangeList::tv(), start);
+        let count = feed.sync(&client, today, &mut cache).await.unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(feed.last_sync(), today);
+        let mut remaining: Vec<_> = cache.keys().copied().collect();
+        remaining.sort();
+        assert_eq!(remaining, vec![2, 4]);
+    }
+
+    #[tokio::test]
+    async fn should_invalidate_client_cache() {
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+
+        use crate::client::cache::Cache;
+
+        #[derive(Clone, Default)]
+        struct Shared(Arc<Mutex<HashMap<String, Arc<str>>>>);
+
+        impl Cache for Shared {
+            fn get(&self, key: &str) -> Option<Arc<str>> {
+                self.0.lock().unwrap().get(key).cloned()
+            }
+
+            fn put(&self, key: &str, value: Arc<str>, _ttl: Duration) {
+                self.0.lock().unwrap().insert(key.to_string(), value);
+            }
+
+            fn remove(&self, key: &str) {
+                self.0.lock().unwrap().remove(key);
+            }
+        }
+
+        let mut server = mockito::Server::new_async().await;
+        let shared = Shared::default();
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_cache(shared.clone(), Duration::from_secs(60))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/changes")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"page":1,"total_pages":1,"total_results":1,"results":[{"id":1,"adult":false}]}"#)
+            .create_async()
+            .await;
+
+        let base_url = server.url();
+        for key in ["/tv/1", "/tv/1?language=fr-FR", "/tv/1/credits", "/tv/2"] {
+            shared.put(
+                &format!("{base_url}{key}"),
+                "{}".into(),
+                Duration::from_secs(60),
+            );
+        }
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let today = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let mut cache = super::ClientCache::new(&client, vec!["/tv/{id}"])
+            .unwrap()
+            .with_queries(vec![String::new(), "language=fr-FR".into()]);
+        let mut feed = ChangeFeed::new(ChangeList::tv(), start);
+        assert_eq!(feed.sync(&client, today, &mut cache).await.unwrap(), 1);
+        let entries = shared.0.lock().unwrap();
+        assert!(!entries.contains_key(&format!("{base_url}/tv/1")));
+        assert!(!entries.contains_key(&format!("{base_url}/tv/1?language=fr-FR")));
+        assert!(entries.contains_key(&format!("{base_url}/tv/1/credits")));
+        assert!(entries.contains_key(&format!("{base_url}/tv/2")));
+    }
+
+    #[tokio::test]
+    async fn should_sync_periodically() {
+        use std::time::Duration;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("GET", "/tv/changes")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"page":1,"total_pages":1,"total_results":1,"results":[{"id":1,"adult":false}]}"#)
+            .expect_at_least(2)
+            .create_async()
+            .await;
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let today = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let mut cache: HashMap<u64, &str> = HashMap::from_iter([(1, "one"), (2, "two")]);
+        let mut feed = ChangeFeed::new(ChangeList::tv(), start);
+        let run = feed.run(&client, &mut cache, Duration::from_millis(10), || today);
+        assert!(tokio::time::timeout(Duration::from_millis(200), run)
+            .await
+            .is_err());
+        m.assert_async().await;
+        assert_eq!(feed.last_sync(), today);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_keep_last_sync_on_error() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/changes")
+            .match_query(Matcher::Any)
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let today = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let mut cache: HashMap<u64, &str> = HashMap::from_iter([(1, "one")]);
+        let mut feed = ChangeFeed::new(ChangeList::tv(), start);
+        assert!(feed.sync(&client, today, &mut cache).await.is_err());
+        assert_eq!(feed.last_sync(), start);
+        assert_eq!(cache.len(), 1);
+    }
+}
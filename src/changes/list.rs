@@ -72,6 +72,10 @@ impl crate::prelude::Command for ChangeList {
         Cow::Borrowed(self.path)
     }
 
+    fn path_template(&self) -> &'static str {
+        self.path
+    }
+
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
         let mut res = Vec::with_capacity(3);
         if let Some(ref start_date) = self.start_date {
@@ -94,7 +98,15 @@ impl crate::prelude::Command for ChangeList {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for ChangeList {
+    type Item = super::Change;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::ChangeList;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,7 +1,12 @@
 #[cfg(feature = "commands")]
+pub mod invalidation;
+#[cfg(feature = "commands")]
 pub mod list;
+#[cfg(feature = "commands")]
+pub mod window;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Change {
     pub id: Option<u64>,
     pub adult: Option<bool>,
@@ -0,0 +1,229 @@
+//! Walk through the changes of an arbitrary date range.
+//!
+//! The changes API only accepts date ranges of up to 14 days, so the range is
+//! split in windows and each window is fetched page by page.
+
+use std::collections::HashSet;
+
+use chrono::{Days, NaiveDate};
+
+use crate::client::{Client, Executor};
+use crate::pagination::Paginator;
+
+use super::list::ChangeList;
+
+/// Maximum number of days accepted by the changes API in a single query.
+pub const MAX_WINDOW_DAYS: u64 = 14;
+
+/// Iterator splitting a date range, both bounds included, into windows
+/// accepted by the changes API.
+#[derive(Clone, Debug)]
+pub struct DateWindows {
+    next: Option<NaiveDate>,
+    end: NaiveDate,
+}
+
+impl DateWindows {
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            next: Some(start).filter(|start| *start <= end),
+            end,
+        }
+    }
+}
+
+impl Iterator for DateWindows {
+    type Item = (NaiveDate, NaiveDate);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next?;
+        let end = start
+            .checked_add_days(Days::new(MAX_WINDOW_DAYS - 1))
+            .map_or(self.end, |end| end.min(self.end));
+        self.next = end.succ_opt().filter(|next| *next <= self.end);
+        Some((start, end))
+    }
+}
+
+/// Fetches the IDs that changed over a date range, skipping the ones that have
+/// already been returned.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use tmdb_api::changes::list::ChangeList;
+/// use tmdb_api::changes::window::ChangeScanner;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+///     let end = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+///     let mut scanner = ChangeScanner::new(ChangeList::movie(), start, end);
+///     loop {
+///         match scanner.next_ids(&client).await {
+///             Ok(Some(ids)) => println!("changed: {:?}", ids),
+///             Ok(None) => break,
+///             Err(err) => {
+///                 eprintln!("error: {:?}", err);
+///                 break;
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChangeScanner {
+    command: ChangeList,
+    windows: DateWindows,
+    current: Option<Paginator<ChangeList>>,
+    seen: HashSet<u64>,
+}
+
+impl ChangeScanner {
+    /// Creates a scanner using the given command for every window, overriding
+    /// its dates and page.
+    pub fn new(command: ChangeList, start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            command,
+            windows: DateWindows::new(start, end),
+            current: None,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Fetches the next page and returns the IDs it contains that haven't been
+    /// returned before, or `None` once all the windows have been fetched.
+    ///
+    /// When the request fails, calling this method again will retry the same page.
+    pub async fn next_ids<E: Executor>(
+        &mut self,
+        client: &Client<E>,
+    ) -> Result<Option<Vec<u64>>, crate::error::Error> {
+        loop {
+            let paginator = match self.current.as_mut() {
+                Some(paginator) => paginator,
+                None => match self.windows.next() {
+                    Some((start, end)) => self.current.insert(Paginator::new(
+                        self.command
+                            .clone()
+                            .with_start_date(Some(start))
+                            .with_end_date(Some(end)),
+                    )),
+                    None => return Ok(None),
+                },
+            };
+            match paginator.next_page(client).await? {
+                Some(page) => {
+                    let ids = page
+                        .results
+                        .into_iter()
+                        .filter_map(|item| item.id)
+                        .filter(|id| self.seen.insert(*id))
+                        .collect();
+                    return Ok(Some(ids));
+                }
+                None => self.current = None,
+            }
+        }
+    }
+
+    /// Fetches all the windows and returns the deduplicated changed IDs.
+    pub async fn collect<E: Executor>(
+        mut self,
+        client: &Client<E>,
+    ) -> Result<Vec<u64>, crate::error::Error> {
+        let mut result = Vec::new();
+        while let Some(ids) = self.next_ids(client).await? {
+            result.extend(ids);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use chrono::NaiveDate;
+    use mockito::Matcher;
+
+    use crate::changes::list::ChangeList;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+
+    use super::{ChangeScanner, DateWindows};
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn should_split_windows() {
+        let windows: Vec<_> = DateWindows::new(date(1, 1), date(2, 1)).collect();
+        assert_eq!(
+            windows,
+            vec![
+                (date(1, 1), date(1, 14)),
+                (date(1, 15), date(1, 28)),
+                (date(1, 29), date(2, 1)),
+            ]
+        );
+        let windows: Vec<_> = DateWindows::new(date(1, 1), date(1, 1)).collect();
+        assert_eq!(windows, vec![(date(1, 1), date(1, 1))]);
+        assert_eq!(DateWindows::new(date(1, 2), date(1, 1)).count(), 0);
+    }
+
+    #[tokio::test]
+    async fn should_deduplicate_ids() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mut mocks = Vec::new();
+        for (start, end, page, body) in [
+            (
+                "2024-01-01",
+                "2024-01-14",
+                "1",
+                r#"{"page":1,"total_pages":2,"total_results":3,"results":[{"id":1,"adult":false},{"id":2,"adult":false}]}"#,
+            ),
+            (
+                "2024-01-01",
+                "2024-01-14",
+                "2",
+                r#"{"page":2,"total_pages":2,"total_results":3,"results":[{"id":3,"adult":false}]}"#,
+            ),
+            (
+                "2024-01-15",
+                "2024-01-20",
+                "1",
+                r#"{"page":1,"total_pages":1,"total_results":3,"results":[{"id":2,"adult":false},{"id":4,"adult":null},{"id":null,"adult":null}]}"#,
+            ),
+        ] {
+            mocks.push(
+                server
+                    .mock("GET", "/movie/changes")
+                    .match_query(Matcher::AllOf(vec![
+                        Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                        Matcher::UrlEncoded("start_date".into(), start.into()),
+                        Matcher::UrlEncoded("end_date".into(), end.into()),
+                        Matcher::UrlEncoded("page".into(), page.into()),
+                    ]))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(body)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let ids = ChangeScanner::new(ChangeList::movie(), date(1, 1), date(1, 20))
+            .collect(&client)
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,357 @@
+//! Executor running a blocking HTTP client, like `ureq`, on a pool of
+//! threads, so that it works with any async runtime.
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Poll, Waker};
+use std::time::Duration;
+
+use super::Method;
+
+/// Blocking HTTP client sending the requests of a [BlockingExecutor].
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::blocking::{BlockingExecutor, Transport};
+/// use tmdb_api::client::Method;
+///
+/// #[derive(Default)]
+/// struct Offline;
+///
+/// impl Transport for Offline {
+///     fn send(
+///         &self,
+///         _method: Method,
+///         _url: &str,
+///         _body: Option<&str>,
+///         _access_token: Option<&str>,
+///     ) -> Result<(u16, bytes::Bytes), tmdb_api::error::Error> {
+///         Ok((200, bytes::Bytes::from_static(b"{}")))
+///     }
+/// }
+///
+/// let client = Client::<BlockingExecutor<Offline>>::new("this-is-my-secret-token".into());
+/// ```
+pub trait Transport: Send + Sync + 'static {
+    /// Sends a request, with its JSON body and the access token to send in
+    /// the `Authorization` header, returning the status code and the body of
+    /// the response.
+    fn send(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&str>,
+        access_token: Option<&str>,
+    ) -> Result<(u16, bytes::Bytes), crate::error::Error>;
+}
+
+/// Executor sending the requests with a blocking [Transport] on a pool of
+/// threads, without blocking the async runtime.
+///
+/// The pool, shared by all the executors, starts at most
+/// [MAX_BLOCKING_THREADS] threads and stops them once idle. The requests sent
+/// while all the threads are busy wait for one to be available.
+#[derive(Debug, Default)]
+pub struct BlockingExecutor<T> {
+    transport: Arc<T>,
+}
+
+impl<T> BlockingExecutor<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport: Arc::new(transport),
+        }
+    }
+
+    /// The underlying transport.
+    pub fn inner(&self) -> &T {
+        &self.transport
+    }
+}
+
+impl<T> From<T> for BlockingExecutor<T> {
+    fn from(transport: T) -> Self {
+        Self::new(transport)
+    }
+}
+
+impl<T: Transport> BlockingExecutor<T> {
+    async fn send(
+        &self,
+        method: Method,
+        url: String,
+        body: Option<String>,
+        access_token: Option<String>,
+    ) -> Result<(u16, bytes::Bytes), crate::error::Error> {
+        let transport = self.transport.clone();
+        run_blocking(move || transport.send(method, &url, body.as_deref(), access_token.as_deref()))
+            .await
+            .map_err(|err| crate::error::Error::Request {
+                source: Box::new(err),
+            })?
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Transport + Default> super::prelude::Executor for BlockingExecutor<T> {
+    async fn execute<O: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<O, crate::error::Error> {
+        let url = super::append_params(url, &params).into_owned();
+        let (status_code, body) = self.send(Method::Get, url.clone(), None, None).await?;
+        super::handle_response(&url, status_code, body)
+    }
+
+    async fn execute_logged<O: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+        body_limit: usize,
+    ) -> Result<O, crate::error::Error> {
+        let url = super::append_params(url, &params).into_owned();
+        let (status_code, body) = self.send(Method::Get, url.clone(), None, None).await?;
+        log::debug!(
+            "response {status_code}: {}",
+            super::truncate_body(&String::from_utf8_lossy(&body), body_limit)
+        );
+        super::handle_response(&url, status_code, body)
+    }
+
+    async fn execute_request<O: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<O, crate::error::Error> {
+        let (status_code, body) = self
+            .send(
+                method,
+                url.to_string(),
+                body,
+                access_token.map(str::to_string),
+            )
+            .await?;
+        super::handle_response(url, status_code, body)
+    }
+}
+
+/// Maximum number of threads running the requests of the [BlockingExecutor].
+pub const MAX_BLOCKING_THREADS: usize = 16;
+
+/// Time after which an idle thread of the pool stops.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Threads started on demand, up to [MAX_BLOCKING_THREADS].
+struct Pool {
+    state: Mutex<PoolState>,
+    available: Condvar,
+}
+
+struct PoolState {
+    jobs: VecDeque<Job>,
+    threads: usize,
+    idle: usize,
+}
+
+static POOL: Pool = Pool {
+    state: Mutex::new(PoolState {
+        jobs: VecDeque::new(),
+        threads: 0,
+        idle: 0,
+    }),
+    available: Condvar::new(),
+};
+
+impl Pool {
+    fn lock(&self) -> std::sync::MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Queues a job, starting a thread for it when none is idle and the pool
+    /// isn't full. Failing to start a thread is only returned when no thread
+    /// is running to pick the job.
+    fn spawn(&'static self, job: Job) -> std::io::Result<()> {
+        let mut state = self.lock();
+        state.jobs.push_back(job);
+        if state.idle >= state.jobs.len() {
+            self.available.notify_one();
+        } else if state.threads < MAX_BLOCKING_THREADS {
+            let started = std::thread::Builder::new()
+                .name("tmdb-api-blocking".into())
+                .spawn(move || self.work());
+            match started {
+                Ok(_) => state.threads += 1,
+                Err(err) if state.threads == 0 => {
+                    state.jobs.pop_back();
+                    return Err(err);
+                }
+                // the job waits for a running thread
+                Err(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn work(&self) {
+        let mut state = self.lock();
+        loop {
+            if let Some(job) = state.jobs.pop_front() {
+                drop(state);
+                job();
+                state = self.lock();
+                continue;
+            }
+            state.idle += 1;
+            let (next, timeout) = self
+                .available
+                .wait_timeout(state, IDLE_TIMEOUT)
+                .unwrap_or_else(|err| err.into_inner());
+            state = next;
+            state.idle -= 1;
+            if timeout.timed_out() && state.jobs.is_empty() {
+                state.threads -= 1;
+                return;
+            }
+        }
+    }
+}
+
+/// Runs a blocking function on the pool of threads, waking the task once
+/// done, independently of the async runtime. A panic of the function is
+/// resumed in the task, and failing to start a thread is returned.
+pub(crate) async fn run_blocking<F, R>(func: F) -> std::io::Result<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let shared = Arc::new(Mutex::new((None::<std::thread::Result<R>>, None::<Waker>)));
+    let thread_shared = shared.clone();
+    POOL.spawn(Box::new(move || {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func));
+        let mut state = thread_shared.lock().unwrap_or_else(|err| err.into_inner());
+        state.0 = Some(result);
+        if let Some(waker) = state.1.take() {
+            waker.wake();
+        }
+    }))?;
+    futures_util::future::poll_fn(move |cx| {
+        let mut state = shared.lock().unwrap_or_else(|err| err.into_inner());
+        match state.0.take() {
+            Some(Ok(result)) => Poll::Ready(Ok(result)),
+            Some(Err(panic)) => std::panic::resume_unwind(panic),
+            None => {
+                state.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use crate::client::{Client, Method};
+    use crate::movie::details::MovieDetails;
+    use crate::prelude::Command;
+
+    use super::{BlockingExecutor, Transport};
+
+    /// Answers with the details of the movie 550, keeping the requests.
+    #[derive(Default)]
+    struct Fixed {
+        requests: Mutex<Vec<String>>,
+    }
+
+    impl Transport for Fixed {
+        fn send(
+            &self,
+            method: Method,
+            url: &str,
+            _body: Option<&str>,
+            _access_token: Option<&str>,
+        ) -> Result<(u16, bytes::Bytes), crate::error::Error> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(format!("{} {url}", method.as_str()));
+            if url.contains("/movie/550?") {
+                Ok((200, include_str!("../../assets/movie-details.json").into()))
+            } else {
+                Ok((
+                    404,
+                    include_str!("../../assets/resource-not-found.json").into(),
+                ))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let client = Client::<BlockingExecutor<Fixed>>::builder()
+            .with_api_key("secret".into())
+            .with_base_url("http://localhost")
+            .build()
+            .unwrap();
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+        let err = MovieDetails::new(1).execute(&client).await.unwrap_err();
+        assert_eq!(err.as_server_error().unwrap().status_code, 34);
+        assert_eq!(
+            *client.executor.inner().requests.lock().unwrap(),
+            vec![
+                "GET http://localhost/movie/550?api_key=secret",
+                "GET http://localhost/movie/1?api_key=secret",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_run_concurrently() {
+        let start = std::time::Instant::now();
+        let tasks = (0..8).map(|index| {
+            super::run_blocking(move || {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                index
+            })
+        });
+        let results = futures_util::future::join_all(tasks).await;
+        assert!(results
+            .into_iter()
+            .enumerate()
+            .all(|(index, result)| index == result.unwrap()));
+        assert!(start.elapsed() < std::time::Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn should_bound_threads() {
+        let running = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let max = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let tasks = (0..super::MAX_BLOCKING_THREADS * 2).map(|_| {
+            let running = running.clone();
+            let max = max.clone();
+            super::run_blocking(move || {
+                let current = running.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                max.fetch_max(current, std::sync::atomic::Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                running.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+            })
+        });
+        let results = futures_util::future::join_all(tasks).await;
+        assert!(results.into_iter().all(|result| result.is_ok()));
+        assert!(max.load(std::sync::atomic::Ordering::SeqCst) <= super::MAX_BLOCKING_THREADS);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "broken")]
+    async fn should_resume_panic() {
+        let _ = super::run_blocking(|| panic!("broken")).await;
+    }
+}
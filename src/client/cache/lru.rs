@@ -0,0 +1,127 @@
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Entries dropped once full, starting with the least recently used ones,
+/// used by the [super::memory::MemoryCache] and by the `ETag` cache of the
+/// reqwest executor.
+pub(crate) struct Lru<V> {
+    capacity: NonZeroUsize,
+    state: Mutex<State<V>>,
+}
+
+struct State<V> {
+    entries: HashMap<String, Entry<V>>,
+    /// Keys of the entries, by last use.
+    usage: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+struct Entry<V> {
+    value: V,
+    /// Never expires when the time to live overflows.
+    expires_at: Option<Instant>,
+    last_used: u64,
+}
+
+/// Keeps the keys out of the logs, given that they can contain the
+/// authorization of the requests.
+impl<V> std::fmt::Debug for Lru<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lru")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<V> State<V> {
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some(entry) = self.entries.get_mut(key) {
+            let key = self
+                .usage
+                .remove(&entry.last_used)
+                .unwrap_or_else(|| key.to_string());
+            entry.last_used = tick;
+            self.usage.insert(tick, key);
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.usage.remove(&entry.last_used);
+        }
+    }
+}
+
+impl<V> Lru<V> {
+    pub(crate) fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(State {
+                entries: HashMap::new(),
+                usage: BTreeMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// Number of entries, expired ones included.
+    pub(crate) fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub(crate) fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.usage.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State<V>> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    pub(crate) fn remove(&self, key: &str) {
+        self.lock().remove(key);
+    }
+}
+
+impl<V: Clone> Lru<V> {
+    pub(crate) fn get_at(&self, key: &str, now: Instant) -> Option<V> {
+        let mut state = self.lock();
+        let expired = state
+            .entries
+            .get(key)?
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= now);
+        if expired {
+            state.remove(key);
+            return None;
+        }
+        state.touch(key);
+        state.entries.get(key).map(|entry| entry.value.clone())
+    }
+
+    pub(crate) fn put_at(&self, key: &str, value: V, expires_at: Option<Instant>) {
+        let mut state = self.lock();
+        state.remove(key);
+        while state.entries.len() >= self.capacity.get() {
+            let Some((_, oldest)) = state.usage.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+        state.entries.insert(
+            key.to_string(),
+            Entry {
+                value,
+                expires_at,
+                last_used: 0,
+            },
+        );
+        state.touch(key);
+    }
+}
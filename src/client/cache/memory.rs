@@ -0,0 +1,120 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::lru::Lru;
+use super::Cache;
+
+/// In memory [Cache], dropping the least recently used bodies once full.
+///
+/// ```rust
+/// use std::num::NonZeroUsize;
+/// use std::time::Duration;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::cache::memory::MemoryCache;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// let client = Client::<ReqwestExecutor>::builder()
+///     .with_api_key("this-is-my-secret-token".into())
+///     .with_cache(
+///         MemoryCache::new(NonZeroUsize::new(1000).unwrap()),
+///         Duration::from_secs(600),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MemoryCache {
+    inner: Lru<Arc<str>>,
+}
+
+impl MemoryCache {
+    /// Keeps at most `capacity` bodies.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            inner: Lru::new(capacity),
+        }
+    }
+
+    /// Number of bodies kept, expired ones included.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.inner.clear();
+    }
+
+    fn get_at(&self, key: &str, now: Instant) -> Option<Arc<str>> {
+        self.inner.get_at(key, now)
+    }
+
+    fn put_at(&self, key: &str, value: Arc<str>, expires_at: Option<Instant>) {
+        self.inner.put_at(key, value, expires_at);
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<Arc<str>> {
+        self.get_at(key, Instant::now())
+    }
+
+    fn put(&self, key: &str, value: Arc<str>, ttl: Duration) {
+        self.put_at(key, value, Instant::now().checked_add(ttl));
+    }
+
+    fn remove(&self, key: &str) {
+        self.inner.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::time::{Duration, Instant};
+
+    use super::MemoryCache;
+
+    #[test]
+    fn should_drop_least_recently_used() {
+        let cache = MemoryCache::new(NonZeroUsize::new(2).unwrap());
+        let now = Instant::now();
+        let later = Some(now + Duration::from_secs(60));
+        cache.put_at("a", "1".into(), later);
+        cache.put_at("b", "2".into(), later);
+        assert_eq!(cache.get_at("a", now).as_deref(), Some("1"));
+        cache.put_at("c", "3".into(), later);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get_at("b", now), None);
+        assert_eq!(cache.get_at("a", now).as_deref(), Some("1"));
+        assert_eq!(cache.get_at("c", now).as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn should_expire_entries() {
+        let cache = MemoryCache::new(NonZeroUsize::new(2).unwrap());
+        let now = Instant::now();
+        cache.put_at("a", "1".into(), Some(now + Duration::from_secs(1)));
+        assert_eq!(cache.get_at("a", now).as_deref(), Some("1"));
+        assert_eq!(cache.get_at("a", now + Duration::from_secs(1)), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn should_remove_entries() {
+        use super::Cache;
+
+        let cache = MemoryCache::new(NonZeroUsize::new(2).unwrap());
+        cache.put("a", "1".into(), Duration::from_secs(60));
+        cache.put("b", "2".into(), Duration::from_secs(60));
+        cache.remove("a");
+        cache.remove("c");
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b").as_deref(), Some("2"));
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -0,0 +1,142 @@
+//! Cache of the responses, consulted by the client before sending the `GET`
+//! requests.
+
+#[cfg(any(feature = "memory-cache", feature = "reqwest"))]
+pub(crate) mod lru;
+#[cfg(feature = "memory-cache")]
+pub mod memory;
+
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Storage of the raw JSON bodies of the responses.
+///
+/// The keys are the urls of the requests, without the api key. Only the
+/// successful `GET` requests that aren't authenticated with the access token
+/// or the session of a user are cached.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::cache::Cache;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// #[derive(Default)]
+/// struct NeverExpires(Mutex<HashMap<String, Arc<str>>>);
+///
+/// impl Cache for NeverExpires {
+///     fn get(&self, key: &str) -> Option<Arc<str>> {
+///         self.0.lock().unwrap().get(key).cloned()
+///     }
+///
+///     fn put(&self, key: &str, value: Arc<str>, _ttl: Duration) {
+///         self.0.lock().unwrap().insert(key.to_string(), value);
+///     }
+///
+///     fn remove(&self, key: &str) {
+///         self.0.lock().unwrap().remove(key);
+///     }
+/// }
+///
+/// let client = Client::<ReqwestExecutor>::builder()
+///     .with_api_key("this-is-my-secret-token".into())
+///     .with_cache(NeverExpires::default(), Duration::from_secs(3600))
+///     .build()
+///     .unwrap();
+/// ```
+pub trait Cache: Send + Sync {
+    /// Body stored for the given key, unless it expired.
+    fn get(&self, key: &str) -> Option<Arc<str>>;
+
+    /// Stores a body, for the given time to live.
+    fn put(&self, key: &str, value: Arc<str>, ttl: Duration);
+
+    /// Evicts the body stored for the given key, if any, like the ones of the
+    /// resources that changed.
+    fn remove(&self, key: &str);
+}
+
+/// Removes the api key from the url of a request, keeping the other
+/// parameters, like the ones added by the interceptors.
+pub(crate) fn cache_key(url: &str) -> Cow<'_, str> {
+    let Some((base, query)) = url.split_once('?') else {
+        return Cow::Borrowed(url);
+    };
+    if !query.split('&').any(|pair| param_name(pair) == "api_key") {
+        return Cow::Borrowed(url);
+    }
+    let mut key = String::with_capacity(url.len());
+    key.push_str(base);
+    let mut separator = '?';
+    for pair in query
+        .split('&')
+        .filter(|pair| param_name(pair) != "api_key")
+    {
+        key.push(separator);
+        key.push_str(pair);
+        separator = '&';
+    }
+    Cow::Owned(key)
+}
+
+/// Whether the url of a request carries the session of a user, making its
+/// response specific to that user.
+pub(crate) fn has_session(url: &str) -> bool {
+    url.split_once('?').is_some_and(|(_, query)| {
+        query
+            .split('&')
+            .any(|pair| matches!(param_name(pair), "session_id" | "guest_session_id"))
+    })
+}
+
+fn param_name(pair: &str) -> &str {
+    pair.split_once('=').map_or(pair, |(name, _)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn should_remove_api_key_from_key() {
+        assert_eq!(
+            super::cache_key("http://host/3/movie/550?api_key=secret"),
+            "http://host/3/movie/550"
+        );
+        assert_eq!(
+            super::cache_key("http://host/3/movie/550?language=fr&api_key=secret"),
+            "http://host/3/movie/550?language=fr"
+        );
+        assert_eq!(
+            super::cache_key("http://host/3/movie/550?language=fr"),
+            "http://host/3/movie/550?language=fr"
+        );
+        assert_eq!(
+            super::cache_key("http://host/3/movie/550?api_key=secret&region=FR&page=2"),
+            "http://host/3/movie/550?region=FR&page=2"
+        );
+        assert_eq!(
+            super::cache_key("http://host/3/movie/550?language=fr&api_key=secret&page=2"),
+            "http://host/3/movie/550?language=fr&page=2"
+        );
+        assert_eq!(
+            super::cache_key("http://host/3/movie/550?my_api_key=abc"),
+            "http://host/3/movie/550?my_api_key=abc"
+        );
+    }
+
+    #[test]
+    fn should_detect_session() {
+        assert!(super::has_session(
+            "http://host/3/account?session_id=abc&api_key=secret"
+        ));
+        assert!(super::has_session(
+            "http://host/3/movie/550/account_states?guest_session_id=abc"
+        ));
+        assert!(!super::has_session(
+            "http://host/3/movie/550?api_key=secret"
+        ));
+        assert!(!super::has_session("http://host/3/account"));
+    }
+}
@@ -0,0 +1,204 @@
+//! Deduplication of the identical requests sent concurrently.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+
+/// Requests being sent, keyed like the [cache](super::cache), that the
+/// identical requests wait for instead of being sent again.
+#[derive(Debug)]
+pub(crate) struct InFlightRequests<T> {
+    entries: Mutex<HashMap<String, Arc<InFlight<T>>>>,
+}
+
+impl<T> Default for InFlightRequests<T> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct InFlight<T> {
+    state: Mutex<InFlightState<T>>,
+}
+
+impl<T> Default for InFlight<T> {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(InFlightState {
+                outcome: None,
+                wakers: Vec::new(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct InFlightState<T> {
+    outcome: Option<Outcome<T>>,
+    wakers: Vec<Waker>,
+}
+
+#[derive(Debug)]
+enum Outcome<T> {
+    Body(T),
+    /// Error that can be cloned for each waiting request.
+    Failed(crate::error::Error),
+    /// The request failed with an error that can't be shared, or got
+    /// cancelled, so each waiting request is sent again.
+    Retry,
+}
+
+impl<T: Clone> InFlight<T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, InFlightState<T>> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn complete(&self, outcome: Outcome<T>) {
+        let mut state = self.lock();
+        state.outcome = Some(outcome);
+        for waker in state.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    /// Waits for the outcome of the request, `None` meaning that it has to be
+    /// sent again.
+    async fn wait(&self) -> Option<Result<T, crate::error::Error>> {
+        futures_util::future::poll_fn(|cx| {
+            let mut state = self.lock();
+            match state.outcome {
+                Some(Outcome::Body(ref body)) => Poll::Ready(Some(Ok(body.clone()))),
+                Some(Outcome::Failed(ref err)) => Poll::Ready(err.try_clone().map(Err)),
+                Some(Outcome::Retry) => Poll::Ready(None),
+                None => {
+                    state.wakers.push(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
+
+/// Completes the request when dropped before getting its response, so that
+/// the waiting requests don't wait forever.
+struct Leader<'a, T: Clone> {
+    requests: &'a InFlightRequests<T>,
+    key: &'a str,
+    entry: Arc<InFlight<T>>,
+    outcome: Option<Outcome<T>>,
+}
+
+impl<T: Clone> Drop for Leader<'_, T> {
+    fn drop(&mut self) {
+        {
+            let mut entries = self.requests.lock();
+            if entries
+                .get(self.key)
+                .is_some_and(|entry| Arc::ptr_eq(entry, &self.entry))
+            {
+                entries.remove(self.key);
+            }
+        }
+        self.entry
+            .complete(self.outcome.take().unwrap_or(Outcome::Retry));
+    }
+}
+
+impl<T: Clone> InFlightRequests<T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<InFlight<T>>>> {
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Sends the request, unless an identical one is already being sent, in
+    /// which case its response is shared.
+    pub(crate) async fn run<F, Fut>(&self, key: &str, send: F) -> Result<T, crate::error::Error>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, crate::error::Error>>,
+    {
+        let (entry, leader) = {
+            let mut entries = self.lock();
+            match entries.get(key) {
+                Some(entry) => (entry.clone(), false),
+                None => {
+                    let entry = Arc::new(InFlight::default());
+                    entries.insert(key.to_string(), entry.clone());
+                    (entry, true)
+                }
+            }
+        };
+        if !leader {
+            return match entry.wait().await {
+                Some(result) => result,
+                None => send().await,
+            };
+        }
+
+        let mut leader = Leader {
+            requests: self,
+            key,
+            entry,
+            outcome: None,
+        };
+        let result = send().await;
+        leader.outcome = Some(match result {
+            Ok(ref body) => Outcome::Body(body.clone()),
+            Err(ref err) => err.try_clone().map_or(Outcome::Retry, Outcome::Failed),
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::Context;
+
+    use super::InFlightRequests;
+
+    #[tokio::test]
+    async fn should_share_response() {
+        let requests = InFlightRequests::default();
+        let count = AtomicUsize::new(0);
+        let send = || async {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Ok(Arc::<str>::from("{}"))
+        };
+        let (first, second) = tokio::join!(requests.run("key", send), requests.run("key", send));
+        assert_eq!(first.unwrap().as_ref(), "{}");
+        assert_eq!(second.unwrap().as_ref(), "{}");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert!(requests.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_retry_when_cancelled() {
+        let requests = InFlightRequests::default();
+        let count = AtomicUsize::new(0);
+        let send = || async {
+            count.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Ok(Arc::<str>::from("{}"))
+        };
+        let leader = requests.run("key", || async {
+            std::future::pending::<Result<Arc<str>, crate::error::Error>>().await
+        });
+        let follower = requests.run("key", send);
+        let (mut leader, mut follower) = (Box::pin(leader), Box::pin(follower));
+        let waker = futures_util::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(leader.as_mut().poll(&mut cx).is_pending());
+        assert!(follower.as_mut().poll(&mut cx).is_pending());
+        drop(leader);
+        assert_eq!(follower.await.unwrap().as_ref(), "{}");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
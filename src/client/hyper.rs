@@ -0,0 +1,278 @@
+use std::borrow::Cow;
+
+use http_body_util::{BodyExt, Full};
+use hyper::header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use hyper_rustls::HttpsConnector;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::TokioExecutor;
+
+type Connector = HttpsConnector<HttpConnector>;
+
+/// Executor based on [hyper], for the applications that don't want the
+/// dependencies of [reqwest](https://docs.rs/reqwest). It requires a tokio
+/// runtime.
+///
+/// An already configured client can be reused with
+/// `HyperExecutor::from(client)`.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::hyper::HyperExecutor;
+///
+/// let client = Client::<HyperExecutor>::new("this-is-my-secret-token".into());
+/// ```
+#[derive(Clone, Debug)]
+pub struct HyperExecutor {
+    inner: hyper_util::client::legacy::Client<Connector, Full<bytes::Bytes>>,
+}
+
+impl Default for HyperExecutor {
+    fn default() -> Self {
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .enable_http2()
+            .build();
+        let inner =
+            hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(connector);
+        Self { inner }
+    }
+}
+
+impl HyperExecutor {
+    /// The underlying hyper client.
+    pub fn inner(&self) -> &hyper_util::client::legacy::Client<Connector, Full<bytes::Bytes>> {
+        &self.inner
+    }
+
+    async fn send(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<(u16, hyper::HeaderMap, bytes::Bytes), crate::error::Error> {
+        let mut req = hyper::Request::builder()
+            .method(request_method(method))
+            .uri(url);
+        if let Some(access_token) = access_token {
+            let value =
+                HeaderValue::try_from(format!("Bearer {access_token}")).map_err(request_error)?;
+            req = req.header(AUTHORIZATION, value);
+        }
+        let body = match body {
+            Some(body) => {
+                req = req.header(CONTENT_TYPE, "application/json;charset=utf-8");
+                Full::new(bytes::Bytes::from(body))
+            }
+            None => Full::default(),
+        };
+        let req = req.body(body).map_err(request_error)?;
+        let res = self.inner.request(req).await.map_err(request_error)?;
+
+        let (parts, body) = res.into_parts();
+        let body = body
+            .collect()
+            .await
+            .map_err(|err| crate::error::Error::Response {
+                source: Box::new(err),
+            })?
+            .to_bytes();
+        Ok((parts.status.as_u16(), parts.headers, body))
+    }
+}
+
+impl From<hyper_util::client::legacy::Client<Connector, Full<bytes::Bytes>>> for HyperExecutor {
+    fn from(inner: hyper_util::client::legacy::Client<Connector, Full<bytes::Bytes>>) -> Self {
+        Self { inner }
+    }
+}
+
+fn request_method(method: super::Method) -> hyper::Method {
+    match method {
+        super::Method::Get => hyper::Method::GET,
+        super::Method::Post => hyper::Method::POST,
+        super::Method::Put => hyper::Method::PUT,
+        super::Method::Delete => hyper::Method::DELETE,
+    }
+}
+
+fn request_error(err: impl std::error::Error + Send + 'static) -> crate::error::Error {
+    crate::error::Error::Request {
+        source: Box::new(err),
+    }
+}
+
+#[async_trait::async_trait]
+impl super::prelude::Executor for HyperExecutor {
+    async fn execute<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<T, crate::error::Error> {
+        let url = super::append_params(url, &params);
+        let (status_code, _, body) = self.send(super::Method::Get, &url, None, None).await?;
+        super::handle_response(&url, status_code, body)
+    }
+
+    async fn execute_logged<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+        body_limit: usize,
+    ) -> Result<T, crate::error::Error> {
+        let url = super::append_params(url, &params);
+        let (status_code, _, body) = self.send(super::Method::Get, &url, None, None).await?;
+        log::debug!(
+            "response {status_code}: {}",
+            super::truncate_body(&String::from_utf8_lossy(&body), body_limit)
+        );
+        super::handle_response(&url, status_code, body)
+    }
+
+    async fn execute_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<T, crate::error::Error> {
+        let (status_code, _, body) = self.send(method, url, body, access_token).await?;
+        super::handle_response(url, status_code, body)
+    }
+
+    /// Waits with the timer of tokio, required by hyper.
+    async fn sleep(&self, duration: std::time::Duration) {
+        tokio::time::sleep(duration).await
+    }
+
+    async fn execute_with_meta<T: serde::de::DeserializeOwned>(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<super::Response<T>, crate::error::Error> {
+        let (status_code, headers, body) = self.send(method, url, body, access_token).await?;
+        Ok(super::Response {
+            status_code,
+            headers: super::response_headers(
+                headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_bytes())),
+            ),
+            body: super::handle_response(url, status_code, body)?,
+        })
+    }
+
+    async fn execute_with_meta_logged<T: serde::de::DeserializeOwned>(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+        body_limit: usize,
+    ) -> Result<super::Response<T>, crate::error::Error> {
+        let (status_code, headers, body) = self.send(method, url, body, access_token).await?;
+        log::debug!(
+            "response {status_code}: {}",
+            super::truncate_body(&String::from_utf8_lossy(&body), body_limit)
+        );
+        Ok(super::Response {
+            status_code,
+            headers: super::response_headers(
+                headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_bytes())),
+            ),
+            body: super::handle_response(url, status_code, body)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::Client;
+    use crate::common::rating::Rating;
+    use crate::movie::details::MovieDetails;
+    use crate::movie::rating::MovieAddRating;
+    use crate::prelude::Command;
+
+    use super::HyperExecutor;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .create_async()
+            .await;
+
+        let client = Client::<HyperExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+    }
+
+    #[tokio::test]
+    async fn should_send_body() {
+        let mut server = mockito::Server::new_async().await;
+        let m = server
+            .mock("POST", "/movie/550/rating")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(serde_json::json!({ "value": 8.5 })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-add-rating.json"))
+            .create_async()
+            .await;
+
+        let client = Client::<HyperExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        let result = MovieAddRating::new(550, Rating::new(8.5).unwrap())
+            .with_session_id(Some("session".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/movie/1")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let client = Client::<HyperExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        let err = MovieDetails::new(1).execute(&client).await.unwrap_err();
+        assert_eq!(err.as_server_error().unwrap().status_code, 34);
+    }
+}
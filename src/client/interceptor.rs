@@ -0,0 +1,51 @@
+//! Hooks observing and modifying the requests sent by the client.
+
+use std::borrow::Cow;
+
+use super::Method;
+
+/// Request about to be sent by the client, once its url is built.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request<'a> {
+    pub method: Method,
+    /// Full url of the request, query included.
+    pub url: String,
+    /// JSON body of the commands writing data.
+    pub body: Option<String>,
+    /// Token sent in the `Authorization` header.
+    pub access_token: Option<Cow<'a, str>>,
+}
+
+/// Interceptor called by the client around every request, for logging,
+/// injecting authentication or checking the requests in tests.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::interceptor::{Request, RequestInterceptor};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// struct Logger;
+///
+/// impl RequestInterceptor for Logger {
+///     fn on_response(&self, request: &Request<'_>, result: Result<(), &tmdb_api::error::Error>) {
+///         println!("{} {}: {:?}", request.method.as_str(), request.url, result.is_ok());
+///     }
+/// }
+///
+/// let client = Client::<ReqwestExecutor>::builder()
+///     .with_api_key("this-is-my-secret-token".into())
+///     .with_interceptor(Logger)
+///     .build()
+///     .unwrap();
+/// ```
+pub trait RequestInterceptor: Send + Sync {
+    /// Called before sending the request, which can be modified.
+    fn on_request(&self, request: &mut Request<'_>) {
+        let _ = request;
+    }
+
+    /// Called once the response is received and deserialized, or failed.
+    fn on_response(&self, request: &Request<'_>, result: Result<(), &crate::error::Error>) {
+        let _ = (request, result);
+    }
+}
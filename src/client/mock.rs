@@ -0,0 +1,234 @@
+//! Executor answering with programmed responses, to test the applications
+//! using this crate without network access.
+
+use std::borrow::Cow;
+use std::sync::Mutex;
+
+use super::Method;
+
+/// Request received by a [MockExecutor].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockRequest {
+    pub method: Method,
+    /// Full url of the request, query included.
+    pub url: String,
+    pub body: Option<String>,
+    pub access_token: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+struct MockResponse {
+    method: Method,
+    path: String,
+    status_code: u16,
+    body: bytes::Bytes,
+}
+
+/// No response was programmed for the request.
+#[derive(Debug, thiserror::Error)]
+#[error("no mock response for {method} {url}")]
+pub struct UnmatchedRequest {
+    pub method: &'static str,
+    pub url: String,
+}
+
+/// Executor answering with the responses programmed for the paths of the
+/// requests, recording all the requests it receives.
+///
+/// A path matches the end of the path of the url, so that the version of the
+/// API in the base url doesn't need to be repeated.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::Method;
+/// use tmdb_api::client::mock::MockExecutor;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let executor = MockExecutor::default().with_response(Method::Get, "/configuration", 200, "{}");
+///     let client = Client::builder()
+///         .with_api_key("secret".into())
+///         .with_executor(executor)
+///         .build()
+///         .unwrap();
+///     assert!(client.ping().await.unwrap().valid_key);
+///     assert_eq!(client.executor().requests().len(), 1);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockExecutor {
+    responses: Mutex<Vec<MockResponse>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockExecutor {
+    /// Answers the requests with the given method and path with the given
+    /// status code and JSON body. The last programmed response wins when
+    /// several match.
+    pub fn with_response<B: Into<bytes::Bytes>>(
+        self,
+        method: Method,
+        path: &str,
+        status_code: u16,
+        body: B,
+    ) -> Self {
+        self.add_response(method, path, status_code, body);
+        self
+    }
+
+    pub fn add_response<B: Into<bytes::Bytes>>(
+        &self,
+        method: Method,
+        path: &str,
+        status_code: u16,
+        body: B,
+    ) {
+        lock(&self.responses).push(MockResponse {
+            method,
+            path: path.to_string(),
+            status_code,
+            body: body.into(),
+        });
+    }
+
+    /// Requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        lock(&self.requests).clone()
+    }
+
+    fn respond<T: serde::de::DeserializeOwned>(
+        &self,
+        request: MockRequest,
+    ) -> Result<T, crate::error::Error> {
+        let path = super::url_path(&request.url);
+        let response = lock(&self.responses)
+            .iter()
+            .rev()
+            .find(|res| res.method == request.method && path_matches(path, &res.path))
+            .cloned();
+        let method = request.method;
+        let url = request.url.clone();
+        lock(&self.requests).push(request);
+        match response {
+            Some(res) => super::handle_response(&url, res.status_code, res.body),
+            None => Err(crate::error::Error::Request {
+                source: Box::new(UnmatchedRequest {
+                    method: method.as_str(),
+                    url,
+                }),
+            }),
+        }
+    }
+}
+
+fn lock<T>(value: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    value.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+fn path_matches(path: &str, expected: &str) -> bool {
+    let expected = expected.trim_end_matches('/');
+    path == expected || (expected.starts_with('/') && path.ends_with(expected))
+}
+
+#[async_trait::async_trait]
+impl super::prelude::Executor for MockExecutor {
+    async fn execute<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<T, crate::error::Error> {
+        self.respond(MockRequest {
+            method: Method::Get,
+            url: super::append_params(url, &params).into_owned(),
+            body: None,
+            access_token: None,
+        })
+    }
+
+    async fn execute_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<T, crate::error::Error> {
+        self.respond(MockRequest {
+            method,
+            url: url.to_string(),
+            body,
+            access_token: access_token.map(str::to_string),
+        })
+    }
+
+    async fn sleep(&self, _duration: std::time::Duration) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{Client, Method};
+    use crate::common::rating::Rating;
+    use crate::movie::details::MovieDetails;
+    use crate::movie::rating::MovieAddRating;
+    use crate::prelude::Command;
+
+    use super::{MockExecutor, MockRequest};
+
+    #[test]
+    fn should_match_paths() {
+        assert_eq!(
+            crate::client::url_path("http://host/3/movie/550?a=b"),
+            "/3/movie/550"
+        );
+        assert_eq!(crate::client::url_path("http://host"), "");
+        assert!(super::path_matches("/3/movie/550", "/movie/550"));
+        assert!(super::path_matches("/3/movie/550", "/3/movie/550/"));
+        assert!(!super::path_matches("/3/movie/1550", "/movie/550"));
+        assert!(!super::path_matches("/3/movie/550/credits", "/movie/550"));
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let executor = MockExecutor::default()
+            .with_response(
+                Method::Get,
+                "/movie/550",
+                200,
+                include_str!("../../assets/movie-details.json"),
+            )
+            .with_response(
+                Method::Post,
+                "/movie/550/rating",
+                201,
+                include_str!("../../assets/movie-add-rating.json"),
+            );
+        let client = Client::builder()
+            .with_api_key("secret".into())
+            .with_base_url("http://localhost/3")
+            .with_executor(executor)
+            .build()
+            .unwrap();
+
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+        let result = MovieAddRating::new(550, Rating::new(8.5).unwrap())
+            .with_session_id(Some("session".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        let err = MovieDetails::new(1).execute(&client).await.unwrap_err();
+        assert!(err.to_string().contains("couldn't execute request"));
+
+        let requests = client.executor().requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[1],
+            MockRequest {
+                method: Method::Post,
+                url: "http://localhost/3/movie/550/rating?session_id=session&api_key=secret".into(),
+                body: Some(r#"{"value":8.5}"#.into()),
+                access_token: None,
+            }
+        );
+    }
+}
@@ -1,24 +1,112 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod blocking;
+pub mod cache;
+mod coalesce;
+#[cfg(feature = "hyper")]
+pub mod hyper;
+pub mod interceptor;
+#[cfg(feature = "test-util")]
+pub mod mock;
 pub mod prelude;
+pub mod rate_limit;
+pub mod replay;
+#[cfg(feature = "reqwest")]
 pub mod reqwest;
 
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Duration;
 
-pub use self::prelude::Executor;
+use self::cache::Cache;
+use self::coalesce::InFlightRequests;
+use self::interceptor::{Request, RequestInterceptor};
+pub use self::prelude::{Executor, Method, Response};
+use self::rate_limit::{RateLimitStatus, RateLimiter};
+pub use crate::util::truncate_body;
+#[cfg(feature = "reqwest")]
 pub type ReqwestClient = Client<reqwest::ReqwestExecutor>;
+#[cfg(feature = "hyper")]
+pub type HyperClient = Client<hyper::HyperExecutor>;
 
 const BASE_URL: &str = "https://api.themoviedb.org/3";
+/// Version suffix of the base url, replaced by the commands of the v4 API.
+const V3_SUFFIX: &str = "/3";
+/// Capacity reserved for the path when building the url of a request.
+const PATH_CAPACITY: usize = 64;
+/// Maximum number of characters of a response body written in the debug logs.
+const DEBUG_BODY_LIMIT: usize = 1024;
+/// Parameters whose values never get written in the logs.
+const SECRET_PARAMS: &[&str] = &["api_key", "session_id", "guest_session_id"];
 
 #[derive(Debug, thiserror::Error)]
 pub enum ClientBuilderError {
-    #[error("missing api key")]
+    #[error("missing api key or read access token")]
     MissingApiKey,
+    #[error("invalid base url {0:?}, expected an http or https url without query")]
+    InvalidBaseUrl(String),
+}
+
+/// Validates the base url and removes its trailing slashes, given that the
+/// command paths start with one. An empty base url falls back to the TMDB one.
+fn normalize_base_url(value: Cow<'static, str>) -> Result<Cow<'static, str>, ClientBuilderError> {
+    if value.is_empty() {
+        return Ok(Cow::Borrowed(BASE_URL));
+    }
+    let host = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"));
+    let valid = host.is_some_and(|host| {
+        !host.is_empty() && !host.starts_with('/') && !host.contains(['?', '#', ' '])
+    });
+    if !valid {
+        return Err(ClientBuilderError::InvalidBaseUrl(value.into_owned()));
+    }
+    if value.ends_with('/') {
+        Ok(Cow::Owned(value.trim_end_matches('/').to_string()))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Way the client authenticates its requests to TMDB.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credentials {
+    /// API key, sent as the `api_key` query parameter.
+    ApiKey(String),
+    /// Read access token of the v4 API, sent in the `Authorization` header so
+    /// that it never shows up in the urls.
+    ReadAccessToken(String),
+}
+
+/// Keeps the secrets out of the logs.
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ApiKey(_) => f.write_str("ApiKey(redacted)"),
+            Self::ReadAccessToken(_) => f.write_str("ReadAccessToken(redacted)"),
+        }
+    }
+}
+
+/// Result of a [Client::ping].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ping {
+    /// Time taken by TMDB to answer.
+    pub latency: Duration,
+    /// Whether TMDB accepted the api key.
+    pub valid_key: bool,
 }
 
 #[derive(Default)]
 pub struct ClientBuilder<E: prelude::Executor> {
     base_url: Cow<'static, str>,
     executor: Option<E>,
-    api_key: Option<String>,
+    credentials: Option<Credentials>,
+    debug_logging: bool,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    cache: Option<(Arc<dyn Cache>, Duration)>,
+    coalesce_requests: bool,
 }
 
 impl<E: prelude::Executor> ClientBuilder<E> {
@@ -41,23 +129,105 @@ impl<E: prelude::Executor> ClientBuilder<E> {
     }
 
     pub fn with_api_key(mut self, value: String) -> Self {
-        self.api_key = Some(value);
+        self.credentials = Some(Credentials::ApiKey(value));
         self
     }
 
     pub fn set_api_key(mut self, value: String) {
-        self.api_key = Some(value);
+        self.credentials = Some(Credentials::ApiKey(value));
+    }
+
+    /// Authenticates with the read access token of the v4 API instead of the
+    /// api key, replacing it if already set.
+    pub fn with_read_access_token(mut self, value: String) -> Self {
+        self.credentials = Some(Credentials::ReadAccessToken(value));
+        self
+    }
+
+    pub fn set_read_access_token(&mut self, value: String) {
+        self.credentials = Some(Credentials::ReadAccessToken(value));
+    }
+
+    /// Logs the requested urls, without their secrets, and the truncated response
+    /// bodies at the debug level. Disabled by default.
+    pub fn with_debug_logging(mut self, value: bool) -> Self {
+        self.debug_logging = value;
+        self
+    }
+
+    pub fn set_debug_logging(&mut self, value: bool) {
+        self.debug_logging = value;
+    }
+
+    /// Delays the requests going over the limit, waiting with
+    /// [Executor::sleep]. Disabled by default.
+    pub fn with_rate_limiter(mut self, value: RateLimiter) -> Self {
+        self.rate_limiter = Some(Arc::new(value));
+        self
+    }
+
+    pub fn set_rate_limiter(&mut self, value: RateLimiter) {
+        self.rate_limiter = Some(Arc::new(value));
+    }
+
+    /// Same as [ClientBuilder::with_rate_limiter], sharing the budget of the
+    /// requests with the other clients using the same limiter.
+    pub fn with_shared_rate_limiter(mut self, value: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(value);
+        self
+    }
+
+    pub fn set_shared_rate_limiter(&mut self, value: Arc<RateLimiter>) {
+        self.rate_limiter = Some(value);
+    }
+
+    /// Adds an interceptor called around every request, after the ones
+    /// already added.
+    pub fn with_interceptor<I: RequestInterceptor + 'static>(mut self, value: I) -> Self {
+        self.interceptors.push(Arc::new(value));
+        self
+    }
+
+    pub fn add_interceptor<I: RequestInterceptor + 'static>(&mut self, value: I) {
+        self.interceptors.push(Arc::new(value));
+    }
+
+    /// Looks up the responses of the `GET` requests in the cache before
+    /// sending them, storing them for `ttl` otherwise. Disabled by default.
+    pub fn with_cache<C: Cache + 'static>(mut self, cache: C, ttl: Duration) -> Self {
+        self.cache = Some((Arc::new(cache), ttl));
+        self
+    }
+
+    pub fn set_cache<C: Cache + 'static>(&mut self, cache: C, ttl: Duration) {
+        self.cache = Some((Arc::new(cache), ttl));
+    }
+
+    /// Sends the identical `GET` requests made concurrently only once, sharing
+    /// the response. Disabled by default.
+    pub fn with_request_coalescing(mut self, value: bool) -> Self {
+        self.coalesce_requests = value;
+        self
+    }
+
+    pub fn set_request_coalescing(&mut self, value: bool) {
+        self.coalesce_requests = value;
     }
 
     pub fn build(self) -> Result<Client<E>, ClientBuilderError> {
-        let base_url = self.base_url;
+        let base_url = normalize_base_url(self.base_url)?;
         let executor = self.executor.unwrap_or_default();
-        let api_key = self.api_key.ok_or(ClientBuilderError::MissingApiKey)?;
+        let credentials = self.credentials.ok_or(ClientBuilderError::MissingApiKey)?;
 
         Ok(Client {
             executor,
             base_url,
-            api_key,
+            credentials,
+            debug_logging: self.debug_logging,
+            rate_limiter: self.rate_limiter,
+            interceptors: self.interceptors,
+            cache: self.cache,
+            in_flight: self.coalesce_requests.then(InFlightRequests::default),
         })
     }
 }
@@ -73,7 +243,12 @@ impl<E: prelude::Executor> ClientBuilder<E> {
 pub struct Client<E> {
     executor: E,
     base_url: Cow<'static, str>,
-    api_key: String,
+    credentials: Credentials,
+    debug_logging: bool,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    cache: Option<(Arc<dyn Cache>, Duration)>,
+    in_flight: Option<InFlightRequests<(u16, Arc<str>)>>,
 }
 
 impl<E: Executor> Client<E> {
@@ -85,7 +260,12 @@ impl<E: Executor> Client<E> {
         Self {
             executor: E::default(),
             base_url: Cow::Borrowed(BASE_URL),
-            api_key,
+            credentials: Credentials::ApiKey(api_key),
+            debug_logging: false,
+            rate_limiter: None,
+            interceptors: Vec::new(),
+            cache: None,
+            in_flight: None,
         }
     }
 
@@ -99,14 +279,1196 @@ impl<E: Executor> Client<E> {
         &self.base_url
     }
 
+    pub fn executor(&self) -> &E {
+        &self.executor
+    }
+
+    /// Cache of the responses, if any.
+    pub(crate) fn cache(&self) -> Option<&Arc<dyn Cache>> {
+        self.cache.as_ref().map(|(cache, _)| cache)
+    }
+
+    /// Rate limiter of the client, to share with other clients.
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Remaining budget of the rate limiter, if any, and how long the next
+    /// request would wait.
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.rate_limiter.as_ref().map(|limiter| limiter.status())
+    }
+
+    /// Read access token sent in the `Authorization` header, if the client
+    /// doesn't use an api key.
+    fn read_access_token(&self) -> Option<&str> {
+        match self.credentials {
+            Credentials::ReadAccessToken(ref value) => Some(value.as_str()),
+            Credentials::ApiKey(_) => None,
+        }
+    }
+
     pub async fn execute<T: serde::de::DeserializeOwned>(
         &self,
         path: &str,
-        mut params: Vec<(&str, Cow<'_, str>)>,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<T, crate::error::Error> {
+        let url = self.build_url(Method::Get, |out| out.push_str(path), &params);
+        self.send(
+            Request {
+                method: Method::Get,
+                url,
+                body: None,
+                access_token: self.read_access_token().map(Cow::Borrowed),
+            },
+            true,
+        )
+        .await
+    }
+
+    /// Executes a `GET` request on a path of the v3 API, returning the
+    /// untyped JSON response, for the endpoints that aren't modeled yet.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use tmdb_api::client::Client;
+    /// use tmdb_api::client::reqwest::ReqwestExecutor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+    ///     let params = vec![("language", Cow::Borrowed("fr-FR"))];
+    ///     match client.execute_raw("/movie/550/alternative_titles", params).await {
+    ///         Ok(res) => println!("found: {res:#}"),
+    ///         Err(err) => eprintln!("error: {:?}", err),
+    ///     };
+    /// }
+    /// ```
+    pub async fn execute_raw(
+        &self,
+        path: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<serde_json::Value, crate::error::Error> {
+        self.execute(path, params).await
+    }
+
+    /// Same as [Client::execute_raw], returning the JSON body as is.
+    pub async fn execute_bytes(
+        &self,
+        path: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<bytes::Bytes, crate::error::Error> {
+        let raw: Box<serde_json::value::RawValue> = self.execute(path, params).await?;
+        Ok(bytes::Bytes::from(Box::<str>::from(raw).into_string()))
+    }
+
+    /// Executes a command, writing its path directly in the url buffer.
+    pub async fn execute_command<C: crate::prelude::Command + ?Sized>(
+        &self,
+        command: &C,
+    ) -> Result<C::Output, crate::error::Error> {
+        let request = self.command_request(command);
+        self.send(request, command.access_token().is_none()).await
+    }
+
+    /// Same as [Client::execute_command], also returning the status code and
+    /// the headers of the response, like its `ETag`. The response is always
+    /// fetched, without going through the cache.
+    ///
+    /// ```rust
+    /// use tmdb_api::client::Client;
+    /// use tmdb_api::client::reqwest::ReqwestExecutor;
+    /// use tmdb_api::movie::details::MovieDetails;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+    ///     match client.execute_command_with_meta(&MovieDetails::new(550)).await {
+    ///         Ok(res) => println!("etag: {:?}, found: {:#?}", res.etag(), res.body),
+    ///         Err(err) => eprintln!("error: {:?}", err),
+    ///     };
+    /// }
+    /// ```
+    pub async fn execute_command_with_meta<C: crate::prelude::Command + ?Sized>(
+        &self,
+        command: &C,
+    ) -> Result<Response<C::Output>, crate::error::Error> {
+        let mut request = self.command_request(command);
+        for interceptor in self.interceptors.iter() {
+            interceptor.on_request(&mut request);
+        }
+        let result = self.dispatch_with_meta(&request).await;
+        for interceptor in self.interceptors.iter() {
+            interceptor.on_response(&request, result.as_ref().map(|_| ()));
+        }
+        result
+    }
+
+    /// Builds the request of a command, writing its path directly in the url
+    /// buffer.
+    fn command_request<'a, C: crate::prelude::Command + ?Sized>(
+        &'a self,
+        command: &'a C,
+    ) -> Request<'a> {
+        let method = command.method();
+        let access_token = command.access_token();
+        let url = if access_token.is_some() {
+            self.build_root_url(method, |out| command.write_path(out), &command.params())
+        } else {
+            self.build_url(method, |out| command.write_path(out), &command.params())
+        };
+        // the access token of the user takes precedence over the one of the client
+        let access_token = access_token.or_else(|| self.read_access_token());
+        Request {
+            method,
+            url,
+            body: command.body().map(|body| body.to_string()),
+            access_token: access_token.map(Cow::Borrowed),
+        }
+    }
+
+    /// Sends a request through the interceptors, the cache and the in flight
+    /// requests, when the request isn't specific to a user, and the rate limiter.
+    /// The requests carrying a session are always specific to a user.
+    async fn send<T: serde::de::DeserializeOwned>(
+        &self,
+        mut request: Request<'_>,
+        cacheable: bool,
+    ) -> Result<T, crate::error::Error> {
+        for interceptor in self.interceptors.iter() {
+            interceptor.on_request(&mut request);
+        }
+        let shared = cacheable
+            && request.method == Method::Get
+            && request.body.is_none()
+            && !cache::has_session(&request.url);
+        let result = if shared && (self.cache.is_some() || self.in_flight.is_some()) {
+            self.fetch_shared(&request)
+                .await
+                .and_then(|(status_code, raw)| {
+                    serde_json::from_str(&raw).map_err(|source| crate::error::Error::Decode {
+                        path: url_path(&request.url).to_string(),
+                        status_code,
+                        body: bytes::Bytes::from(raw.to_string()),
+                        source,
+                    })
+                })
+        } else {
+            self.dispatch(&request).await
+        };
+        for interceptor in self.interceptors.iter() {
+            interceptor.on_response(&request, result.as_ref().map(|_| ()));
+        }
+        result
+    }
+
+    /// Fetches the raw body of a request that isn't specific to a user, from
+    /// the cache or from the identical request already being sent if any,
+    /// with the status code of its response.
+    async fn fetch_shared(
+        &self,
+        request: &Request<'_>,
+    ) -> Result<(u16, Arc<str>), crate::error::Error> {
+        let key = cache::cache_key(&request.url);
+        if let Some((ref cache, _)) = self.cache {
+            // only the successful responses get cached
+            if let Some(raw) = cache.get(&key) {
+                return Ok((200, raw));
+            }
+        }
+        let send = || async {
+            self.dispatch_with_meta::<Box<serde_json::value::RawValue>>(request)
+                .await
+                .map(|res| (res.status_code, Arc::<str>::from(res.body.get())))
+        };
+        let (status_code, raw) = match self.in_flight {
+            Some(ref in_flight) => in_flight.run(&key, send).await?,
+            None => send().await?,
+        };
+        if let Some((ref cache, ttl)) = self.cache {
+            cache.put(&key, raw.clone(), ttl);
+        }
+        Ok((status_code, raw))
+    }
+
+    /// Same as [Client::dispatch], also returning the status code and the
+    /// headers of the response.
+    async fn dispatch_with_meta<T: serde::de::DeserializeOwned>(
+        &self,
+        request: &Request<'_>,
+    ) -> Result<Response<T>, crate::error::Error> {
+        self.wait_rate_limit().await;
+        if self.debug_logging {
+            self.executor
+                .execute_with_meta_logged(
+                    request.method,
+                    &request.url,
+                    request.body.clone(),
+                    request.access_token.as_deref(),
+                    DEBUG_BODY_LIMIT,
+                )
+                .await
+        } else {
+            self.executor
+                .execute_with_meta(
+                    request.method,
+                    &request.url,
+                    request.body.clone(),
+                    request.access_token.as_deref(),
+                )
+                .await
+        }
+    }
+
+    /// Sends a request to the executor, once allowed by the rate limiter.
+    async fn dispatch<T: serde::de::DeserializeOwned>(
+        &self,
+        request: &Request<'_>,
+    ) -> Result<T, crate::error::Error> {
+        self.wait_rate_limit().await;
+        match (
+            request.method,
+            request.body.as_ref(),
+            request.access_token.as_deref(),
+        ) {
+            (Method::Get, None, None) => self.execute_url(&request.url).await,
+            (method, body, access_token) => {
+                self.executor
+                    .execute_request(method, &request.url, body.cloned(), access_token)
+                    .await
+            }
+        }
+    }
+
+    /// Waits until the rate limiter, if any, lets the next request go.
+    async fn wait_rate_limit(&self) {
+        if let Some(delay) = self
+            .rate_limiter
+            .as_ref()
+            .and_then(|limiter| limiter.reserve())
+        {
+            self.executor.sleep(delay).await;
+        }
+    }
+
+    async fn execute_url<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
     ) -> Result<T, crate::error::Error> {
-        params.push(("api_key", Cow::Borrowed(self.api_key.as_str())));
+        // the query is already encoded in the url, so the executor doesn't need to
+        // serialize the params again
+        if self.debug_logging {
+            self.executor
+                .execute_logged(url, Vec::new(), DEBUG_BODY_LIMIT)
+                .await
+        } else {
+            self.executor.execute(url, Vec::new()).await
+        }
+    }
+
+    /// Performs a lightweight authenticated request, on `/configuration`, and
+    /// measures how long TMDB takes to answer. An invalid api key isn't considered
+    /// as an error, any other failure is.
+    ///
+    /// ```rust
+    /// use tmdb_api::client::Client;
+    /// use tmdb_api::client::reqwest::ReqwestExecutor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+    ///     match client.ping().await {
+    ///         Ok(res) => println!("valid key: {}, latency: {:?}", res.valid_key, res.latency),
+    ///         Err(err) => eprintln!("error: {:?}", err),
+    ///     };
+    /// }
+    /// ```
+    pub async fn ping(&self) -> Result<Ping, crate::error::Error> {
+        // the clock of the standard library panics on wasm32
+        let start = rate_limit::Instant::now();
+        let result = self
+            .execute::<serde::de::IgnoredAny>("/configuration", Vec::new())
+            .await;
+        let latency = rate_limit::Instant::now().saturating_duration_since(start);
+        match result {
+            Ok(_) => Ok(Ping {
+                latency,
+                valid_key: true,
+            }),
+            Err(crate::error::Error::Server { code: 401, .. }) => Ok(Ping {
+                latency,
+                valid_key: false,
+            }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Builds the url of a request, writing the path, the params and the api key
+    /// directly into a single buffer.
+    pub(crate) fn build_url<P: FnOnce(&mut String)>(
+        &self,
+        method: Method,
+        write_path: P,
+        params: &[(&str, Cow<'_, str>)],
+    ) -> String {
+        self.build_url_from(&self.base_url, method, write_path, params)
+    }
+
+    /// Same as [Client::build_url] for the paths starting with the version of
+    /// the API, like the v4 ones, by removing the v3 version from the base url.
+    fn build_root_url<P: FnOnce(&mut String)>(
+        &self,
+        method: Method,
+        write_path: P,
+        params: &[(&str, Cow<'_, str>)],
+    ) -> String {
+        let root = self
+            .base_url
+            .strip_suffix(V3_SUFFIX)
+            .unwrap_or(&self.base_url);
+        self.build_url_from(root, method, write_path, params)
+    }
+
+    fn build_url_from<P: FnOnce(&mut String)>(
+        &self,
+        base_url: &str,
+        method: Method,
+        write_path: P,
+        params: &[(&str, Cow<'_, str>)],
+    ) -> String {
+        let capacity = params.iter().fold(
+            base_url.len() + PATH_CAPACITY + 9 + self.api_key().map_or(0, str::len),
+            |acc, (key, value)| acc + key.len() + value.len() + 2,
+        );
+        let mut url = String::with_capacity(capacity);
+        url.push_str(base_url);
+        write_path(&mut url);
+        if self.debug_logging {
+            log::debug!(
+                "{} {}",
+                method.as_str(),
+                sanitized_url(&url, params, self.api_key().is_some())
+            );
+        }
+        let api_key = self.api_key();
+        if params.is_empty() && api_key.is_none() {
+            return url;
+        }
+        url.push('?');
+        let start = url.len();
+        let mut serializer = form_urlencoded::Serializer::for_suffix(&mut url, start);
+        serializer.extend_pairs(params.iter().map(|(key, value)| (key, value.as_ref())));
+        if let Some(api_key) = api_key {
+            serializer.append_pair("api_key", api_key);
+        }
+        serializer.finish();
+        url
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        match self.credentials {
+            Credentials::ApiKey(ref value) => Some(value.as_str()),
+            Credentials::ReadAccessToken(_) => None,
+        }
+    }
+}
+
+/// Builds the url of a request, hiding the values of the secret parameters and
+/// the api key, when sent.
+fn sanitized_url(url: &str, params: &[(&str, Cow<'_, str>)], with_api_key: bool) -> String {
+    let mut res = url.to_string();
+    let mut separator = '?';
+    for (key, value) in params.iter() {
+        res.push(separator);
+        res.push_str(key);
+        res.push('=');
+        if SECRET_PARAMS.contains(key) {
+            res.push_str("redacted");
+        } else {
+            res.push_str(value);
+        }
+        separator = '&';
+    }
+    if with_api_key {
+        res.push(separator);
+        res.push_str("api_key=redacted");
+    }
+    res
+}
+
+/// Appends the params to the query of the url, for the executors.
+pub(crate) fn append_params<'a>(url: &'a str, params: &[(&str, Cow<'_, str>)]) -> Cow<'a, str> {
+    if params.is_empty() {
+        return Cow::Borrowed(url);
+    }
+    let mut url = url.to_string();
+    url.push(if url.contains('?') { '&' } else { '?' });
+    let start = url.len();
+    form_urlencoded::Serializer::for_suffix(&mut url, start)
+        .extend_pairs(params.iter().map(|(key, value)| (key, value.as_ref())))
+        .finish();
+    Cow::Owned(url)
+}
+
+/// Converts the headers of a response, for the executors implementing
+/// [Executor::execute_with_meta].
+#[cfg(any(feature = "reqwest", feature = "hyper"))]
+pub(crate) fn response_headers<'a>(
+    headers: impl Iterator<Item = (&'a str, &'a [u8])>,
+) -> Vec<(String, String)> {
+    headers
+        .map(|(name, value)| {
+            let value = String::from_utf8_lossy(value).into_owned();
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Path of a url, without its scheme, host and query, to identify a request
+/// without its secret parameters.
+pub(crate) fn url_path(url: &str) -> &str {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let url = url.find('/').map_or("", |index| &url[index..]);
+    url.split(['?', '#']).next().unwrap_or(url)
+}
+
+/// Deserializes a response body, keeping it in the error when it fails.
+fn decode<T: serde::de::DeserializeOwned>(
+    url: &str,
+    status_code: u16,
+    body: bytes::Bytes,
+) -> Result<T, crate::error::Error> {
+    serde_json::from_slice(&body).map_err(|source| crate::error::Error::Decode {
+        path: url_path(url).to_string(),
+        status_code,
+        body,
+        source,
+    })
+}
+
+/// Builds the result of a response, from the url of its request, its status
+/// code and its body, for the executors.
+pub(crate) fn handle_response<T: serde::de::DeserializeOwned>(
+    url: &str,
+    status_code: u16,
+    body: bytes::Bytes,
+) -> Result<T, crate::error::Error> {
+    match status_code {
+        200..=299 => decode(url, status_code, body),
+        422 => Err(crate::error::Error::Validation(decode(
+            url,
+            status_code,
+            body,
+        )?)),
+        code => Err(crate::error::Error::Server {
+            code,
+            content: decode(url, status_code, body)?,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    #[cfg(feature = "reqwest")]
+    use super::Method;
+
+    #[test]
+    fn should_sanitize_url() {
+        let url = super::sanitized_url(
+            "https://api.themoviedb.org/3/account",
+            &[
+                ("session_id", Cow::Borrowed("abcd")),
+                ("page", Cow::Borrowed("2")),
+            ],
+            true,
+        );
+        assert_eq!(
+            url,
+            "https://api.themoviedb.org/3/account?session_id=redacted&page=2&api_key=redacted"
+        );
+    }
+
+    #[test]
+    fn should_sanitize_url_without_api_key() {
+        let url = super::sanitized_url(
+            "https://api.themoviedb.org/3/account",
+            &[("session_id", Cow::Borrowed("abcd"))],
+            false,
+        );
+        assert_eq!(
+            url,
+            "https://api.themoviedb.org/3/account?session_id=redacted"
+        );
+        let url = super::sanitized_url("https://api.themoviedb.org/3/configuration", &[], false);
+        assert_eq!(url, "https://api.themoviedb.org/3/configuration");
+    }
+
+    #[test]
+    fn should_append_params() {
+        assert_eq!(
+            super::append_params("http://host/movie/550", &[]),
+            "http://host/movie/550"
+        );
+        assert_eq!(
+            super::append_params(
+                "http://host/movie/550?api_key=secret",
+                &[("language", Cow::Borrowed("fr FR"))]
+            ),
+            "http://host/movie/550?api_key=secret&language=fr+FR"
+        );
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn should_build_url() {
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let client = super::Client::<ReqwestExecutor>::new("secret".into());
+        assert_eq!(
+            client.build_url(Method::Get, |out| out.push_str("/movie/550"), &[]),
+            "https://api.themoviedb.org/3/movie/550?api_key=secret"
+        );
+        assert_eq!(
+            client.build_url(
+                Method::Get,
+                |out| out.push_str("/search/movie"),
+                &[
+                    ("query", Cow::Borrowed("fight club & co")),
+                    ("language", Cow::Borrowed("fr-FR")),
+                ]
+            ),
+            "https://api.themoviedb.org/3/search/movie?query=fight+club+%26+co&language=fr-FR&api_key=secret"
+        );
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn should_build_url_without_api_key() {
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_read_access_token("token".into())
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.build_url(Method::Get, |out| out.push_str("/movie/550"), &[]),
+            "https://api.themoviedb.org/3/movie/550"
+        );
+        assert_eq!(
+            client.build_url(
+                Method::Get,
+                |out| out.push_str("/movie/550"),
+                &[("language", Cow::Borrowed("fr-FR"))]
+            ),
+            "https://api.themoviedb.org/3/movie/550?language=fr-FR"
+        );
+        assert_eq!(
+            format!("{:?}", client.credentials),
+            "ReadAccessToken(redacted)"
+        );
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn read_access_token_works() {
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::movie::details::MovieDetails;
+        use crate::prelude::Command;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_read_access_token("token".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_header("authorization", "Bearer token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .create_async()
+            .await;
+        let _ping = server
+            .mock("GET", "/configuration")
+            .match_header("authorization", "Bearer token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"change_keys":[],"images":{"base_url":"http://image.tmdb.org/t/p/"}}"#)
+            .create_async()
+            .await;
+
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+        assert!(client.ping().await.unwrap().valid_key);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn rate_limiter_delays_requests() {
+        use std::num::NonZeroU32;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::time::{Duration, Instant};
+
+        use crate::client::rate_limit::RateLimiter;
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let delayed = Arc::new(AtomicUsize::new(0));
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap()).with_delay_callback({
+            let delayed = delayed.clone();
+            move |_| {
+                delayed.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_rate_limiter(limiter)
+            .build()
+            .unwrap();
+        assert_eq!(client.rate_limit_status().unwrap().remaining, 2);
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{}")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert!(client.ping().await.unwrap().valid_key);
+        }
+        assert!(start.elapsed() >= Duration::from_millis(500));
+        assert_eq!(delayed.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn shared_rate_limiter_delays_requests() {
+        use std::num::NonZeroU32;
+        use std::sync::Arc;
+        use std::time::{Duration, Instant};
+
+        use crate::client::rate_limit::RateLimiter;
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let mut server = mockito::Server::new_async().await;
+        let limiter = Arc::new(
+            RateLimiter::new(NonZeroU32::new(4).unwrap()).with_burst(NonZeroU32::new(2).unwrap()),
+        );
+        let first = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_shared_rate_limiter(limiter.clone())
+            .build()
+            .unwrap();
+        let second = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_shared_rate_limiter(first.rate_limiter().unwrap().clone())
+            .build()
+            .unwrap();
+        assert!(Arc::ptr_eq(second.rate_limiter().unwrap(), &limiter));
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{}")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let start = Instant::now();
+        assert!(first.ping().await.unwrap().valid_key);
+        assert!(second.ping().await.unwrap().valid_key);
+        assert!(first.ping().await.unwrap().valid_key);
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn interceptors_are_called() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::client::interceptor::{Request, RequestInterceptor};
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::movie::details::MovieDetails;
+        use crate::prelude::Command;
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<String>>);
+
+        impl RequestInterceptor for Arc<Recorder> {
+            fn on_request(&self, request: &mut Request<'_>) {
+                request.url.push_str("&language=fr-FR");
+            }
+
+            fn on_response(&self, request: &Request<'_>, result: Result<(), &crate::error::Error>) {
+                self.0.lock().unwrap().push(format!(
+                    "{} {} {}",
+                    request.method.as_str(),
+                    request.url.split('?').next().unwrap(),
+                    result.is_ok()
+                ));
+            }
+        }
+
+        let mut server = mockito::Server::new_async().await;
+        let recorder = Arc::new(Recorder::default());
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_interceptor(recorder.clone())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                mockito::Matcher::UrlEncoded("language".into(), "fr-FR".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .create_async()
+            .await;
+        let _missing = server
+            .mock("GET", "/movie/1")
+            .match_query(mockito::Matcher::Any)
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        MovieDetails::new(550).execute(&client).await.unwrap();
+        MovieDetails::new(1).execute(&client).await.unwrap_err();
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec![
+                format!("GET {}/movie/550 true", server.url()),
+                format!("GET {}/movie/1 false", server.url()),
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "memory-cache", feature = "reqwest"))]
+    #[tokio::test]
+    async fn cache_skips_requests() {
+        use std::num::NonZeroUsize;
+        use std::time::Duration;
+
+        use crate::client::cache::memory::MemoryCache;
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::movie::details::MovieDetails;
+        use crate::movie::rating::MovieDeleteRating;
+        use crate::prelude::Command;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_cache(
+                MemoryCache::new(NonZeroUsize::new(10).unwrap()),
+                Duration::from_secs(60),
+            )
+            .build()
+            .unwrap();
+
+        let details = server
+            .mock("GET", "/movie/550")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "api_key".into(),
+                "secret".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .expect(1)
+            .create_async()
+            .await;
+        let rating = server
+            .mock("DELETE", "/movie/550/rating")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-delete-rating.json"))
+            .expect(2)
+            .create_async()
+            .await;
+
+        for _ in 0..2 {
+            let result = MovieDetails::new(550).execute(&client).await.unwrap();
+            assert_eq!(result.inner.id, 550);
+            MovieDeleteRating::new(550).execute(&client).await.unwrap();
+        }
+        details.assert_async().await;
+        rating.assert_async().await;
+    }
+
+    #[cfg(all(feature = "memory-cache", feature = "reqwest"))]
+    #[tokio::test]
+    async fn cache_skips_session_requests() {
+        use std::num::NonZeroUsize;
+        use std::time::Duration;
+
+        use crate::account::details::AccountDetails;
+        use crate::client::cache::memory::MemoryCache;
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::prelude::Command;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_cache(
+                MemoryCache::new(NonZeroUsize::new(10).unwrap()),
+                Duration::from_secs(60),
+            )
+            .with_request_coalescing(true)
+            .build()
+            .unwrap();
+
+        let account = server
+            .mock("GET", "/account")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "session_id".into(),
+                "session".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-details.json"))
+            .expect(2)
+            .create_async()
+            .await;
+
+        for _ in 0..2 {
+            AccountDetails::new("session".into())
+                .execute(&client)
+                .await
+                .unwrap();
+        }
+        account.assert_async().await;
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn identical_requests_are_coalesced() {
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::movie::details::MovieDetails;
+        use crate::prelude::Command;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_request_coalescing(true)
+            .build()
+            .unwrap();
+
+        let details = server
+            .mock("GET", "/movie/550")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "api_key".into(),
+                "secret".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .expect(1)
+            .create_async()
+            .await;
+        let missing = server
+            .mock("GET", "/movie/1")
+            .match_query(mockito::Matcher::Any)
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cmd = MovieDetails::new(550);
+        let (first, second) = tokio::join!(cmd.execute(&client), cmd.execute(&client));
+        assert_eq!(first.unwrap().inner.id, 550);
+        assert_eq!(second.unwrap().inner.id, 550);
+        let cmd = MovieDetails::new(1);
+        let (first, second) = tokio::join!(cmd.execute(&client), cmd.execute(&client));
+        assert!(first.unwrap_err().is_not_found());
+        assert!(second.unwrap_err().is_not_found());
+        details.assert_async().await;
+        missing.assert_async().await;
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn coalesced_decode_error_keeps_status_code() {
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::movie::details::MovieDetails;
+        use crate::prelude::Command;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_request_coalescing(true)
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_query(mockito::Matcher::Any)
+            .with_status(203)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"not-a-number"}"#)
+            .create_async()
+            .await;
+
+        let err = MovieDetails::new(550).execute(&client).await.unwrap_err();
+        match err {
+            crate::error::Error::Decode {
+                path, status_code, ..
+            } => {
+                assert_eq!(path, "/movie/550");
+                assert_eq!(status_code, 203);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn execute_raw_works() {
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                mockito::Matcher::UrlEncoded("language".into(), "fr-FR".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":550,"title":"Fight Club"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let params = || vec![("language", Cow::Borrowed("fr-FR"))];
+        let result = client.execute_raw("/movie/550", params()).await.unwrap();
+        assert_eq!(result["title"], "Fight Club");
+        let result = client.execute_bytes("/movie/550", params()).await.unwrap();
+        assert_eq!(result, r#"{"id":550,"title":"Fight Club"}"#);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn should_build_root_url() {
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let client = super::Client::<ReqwestExecutor>::new("secret".into());
+        assert_eq!(
+            client.build_root_url(Method::Post, |out| out.push_str("/4/list"), &[]),
+            "https://api.themoviedb.org/4/list?api_key=secret"
+        );
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn should_write_command_path() {
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::prelude::Command;
+        use crate::tvshow::episode::details::TVShowEpisodeDetails;
+
+        let client = super::Client::<ReqwestExecutor>::new("secret".into());
+        let command = TVShowEpisodeDetails::new(1399, 1, 2);
+        assert_eq!(command.path(), "/tv/1399/season/1/episode/2");
+        assert_eq!(
+            client.build_url(
+                Method::Get,
+                |out| command.write_path(out),
+                &command.params()
+            ),
+            "https://api.themoviedb.org/3/tv/1399/season/1/episode/2?api_key=secret"
+        );
+    }
+
+    #[test]
+    fn should_normalize_base_url() {
+        use super::{normalize_base_url, ClientBuilderError, BASE_URL};
+
+        assert_eq!(normalize_base_url("".into()).unwrap(), BASE_URL);
+        assert_eq!(
+            normalize_base_url("http://localhost:1234/".into()).unwrap(),
+            "http://localhost:1234"
+        );
+        assert_eq!(
+            normalize_base_url("https://proxy.example.com/tmdb/3//".into()).unwrap(),
+            "https://proxy.example.com/tmdb/3"
+        );
+        for value in [
+            "api.themoviedb.org/3",
+            "ftp://api.themoviedb.org/3",
+            "https://",
+            "https:///3",
+            "https://api.themoviedb.org/3?language=fr",
+        ] {
+            assert!(matches!(
+                normalize_base_url(value.into()),
+                Err(ClientBuilderError::InvalidBaseUrl(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn build_should_reject_invalid_base_url() {
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let err = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url("localhost:1234")
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid base url \"localhost:1234\", expected an http or https url without query"
+        );
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url(), super::BASE_URL);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn debug_logging_works() {
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::movie::details::MovieDetails;
+        use crate::prelude::Command;
+
+        let mut server = mockito::Server::new_async().await;
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_debug_logging(true)
+            .build()
+            .unwrap();
+
+        let _found = server
+            .mock("GET", "/movie/550")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "api_key".into(),
+                "secret".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .create_async()
+            .await;
+        let _not_found = server
+            .mock("GET", "/movie/1")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "api_key".into(),
+                "secret".into(),
+            ))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+        let err = MovieDetails::new(1).execute(&client).await.unwrap_err();
+        assert_eq!(err.as_server_error().unwrap().status_code, 34);
+        let result = client
+            .execute_command_with_meta(&MovieDetails::new(550))
+            .await
+            .unwrap();
+        assert_eq!(result.status_code, 200);
+        assert_eq!(result.body.inner.id, 550);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn ping_works() {
+        use crate::client::reqwest::ReqwestExecutor;
+
+        let mut server = mockito::Server::new_async().await;
+        let _valid = server
+            .mock("GET", "/configuration")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "api_key".into(),
+                "secret".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"change_keys":[],"images":{"base_url":"http://image.tmdb.org/t/p/"}}"#)
+            .create_async()
+            .await;
+        let _invalid = server
+            .mock("GET", "/configuration")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "api_key".into(),
+                "wrong".into(),
+            ))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        assert!(client.ping().await.unwrap().valid_key);
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("wrong".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        assert!(!client.ping().await.unwrap().valid_key);
+
+        let client = super::Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url("http://127.0.0.1:1")
+            .build()
+            .unwrap();
+        assert!(client.ping().await.is_err());
+    }
 
-        let url = format!("{}{}", self.base_url, path);
-        self.executor.execute(&url, params).await
+    #[test]
+    fn should_truncate_body() {
+        assert_eq!(super::truncate_body("hello", 10), "hello");
+        assert_eq!(super::truncate_body("hello", 5), "hello");
+        assert_eq!(
+            super::truncate_body("héllo world", 5),
+            "héllo... (6 more bytes)"
+        );
     }
 }
@@ -22,6 +22,62 @@
 
 use std::borrow::Cow;
 
+/// HTTP method of a request, most of the commands only read data with `GET`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Method {
+    #[default]
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+/// Deserialized body of a response, along with its status code and headers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response<T> {
+    pub status_code: u16,
+    /// Headers of the response, with lowercase names.
+    pub headers: Vec<(String, String)>,
+    pub body: T,
+}
+
+impl<T> Response<T> {
+    /// Value of the first header with the given name, ignoring its case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.header("etag")
+    }
+
+    pub fn cache_control(&self) -> Option<&str> {
+        self.header("cache-control")
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, func: F) -> Response<U> {
+        Response {
+            status_code: self.status_code,
+            headers: self.headers,
+            body: func(self.body),
+        }
+    }
+}
+
 #[async_trait::async_trait]
 pub trait Executor: Default + Send + Sync {
     async fn execute<T: serde::de::DeserializeOwned>(
@@ -29,4 +85,86 @@ pub trait Executor: Default + Send + Sync {
         url: &str,
         params: Vec<(&str, Cow<'_, str>)>,
     ) -> Result<T, crate::error::Error>;
+
+    /// Same as [Executor::execute], but also logs the response body, truncated to
+    /// `body_limit` characters, at the debug level.
+    ///
+    /// Executors that can't access the raw body fall back to [Executor::execute].
+    async fn execute_logged<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+        body_limit: usize,
+    ) -> Result<T, crate::error::Error> {
+        let _ = body_limit;
+        self.execute(url, params).await
+    }
+
+    /// Sends a request with the given method and JSON body, for the commands
+    /// writing data, authenticated with the access token of the user when given.
+    ///
+    /// Executors that only support reading data fall back to [Executor::execute]
+    /// for the `GET` requests without body nor access token and fail otherwise.
+    async fn execute_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<T, crate::error::Error> {
+        match (method, body, access_token) {
+            (Method::Get, None, None) => self.execute(url, Vec::new()).await,
+            _ => Err(crate::error::Error::UnsupportedMethod(method.as_str())),
+        }
+    }
+
+    /// Waits before sending a request limited by the
+    /// [RateLimiter](crate::client::rate_limit::RateLimiter) of the client.
+    ///
+    /// The default implementation waits on a single timer thread shared by
+    /// all the clients, which works with any runtime, but the executors should
+    /// rely on the timer of their runtime instead. On `wasm32`, it waits with
+    /// `setTimeout`.
+    async fn sleep(&self, duration: std::time::Duration) {
+        crate::client::rate_limit::sleep(duration).await
+    }
+
+    /// Same as [Executor::execute_request], also returning the status code and
+    /// the headers of the response.
+    ///
+    /// Executors that can't access them answer with a `200` status code and
+    /// no header.
+    async fn execute_with_meta<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<Response<T>, crate::error::Error> {
+        self.execute_request(method, url, body, access_token)
+            .await
+            .map(|body| Response {
+                status_code: 200,
+                headers: Vec::new(),
+                body,
+            })
+    }
+
+    /// Same as [Executor::execute_with_meta], but also logs the response body,
+    /// truncated to `body_limit` characters, at the debug level.
+    ///
+    /// Executors that can't access the raw body fall back to
+    /// [Executor::execute_with_meta].
+    async fn execute_with_meta_logged<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+        body_limit: usize,
+    ) -> Result<Response<T>, crate::error::Error> {
+        let _ = body_limit;
+        self.execute_with_meta(method, url, body, access_token)
+            .await
+    }
 }
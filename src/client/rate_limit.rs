@@ -0,0 +1,375 @@
+//! Rate limiting of the requests, independent of the async runtime.
+//!
+//! The limiter only computes how long a request has to wait, the waiting
+//! itself goes through [Executor::sleep](crate::client::Executor::sleep) so
+//! that each executor can rely on the timer of its runtime.
+
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use self::wasm::sleep;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use self::wasm::Instant;
+
+/// Token bucket limiting the number of requests sent per second.
+///
+/// The limiter can be shared between several clients, using the same api key,
+/// with [ClientBuilder::with_shared_rate_limiter](crate::client::ClientBuilder::with_shared_rate_limiter).
+///
+/// ```rust
+/// use std::num::NonZeroU32;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::rate_limit::RateLimiter;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// let client = Client::<ReqwestExecutor>::builder()
+///     .with_api_key("this-is-my-secret-token".into())
+///     .with_rate_limiter(RateLimiter::new(NonZeroU32::new(40).unwrap()))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct RateLimiter {
+    per_second: f64,
+    burst: f64,
+    state: Mutex<State>,
+    on_delay: Option<DelayCallback>,
+}
+
+/// Callback called with the delay of each request waiting for the limiter.
+type DelayCallback = Arc<dyn Fn(Duration) + Send + Sync>;
+
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("per_second", &self.per_second)
+            .field("burst", &self.burst)
+            .field("state", &self.state)
+            .field("on_delay", &self.on_delay.is_some())
+            .finish()
+    }
+}
+
+/// Budget left in a [RateLimiter], returned by [RateLimiter::status].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimitStatus {
+    /// Number of requests that can be sent right away.
+    pub remaining: u32,
+    /// Estimated time the next request would have to wait, zero when some
+    /// budget remains.
+    pub wait: Duration,
+}
+
+#[derive(Debug)]
+struct State {
+    /// Available tokens, negative when requests are already waiting.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Allows `per_second` requests per second, all of them at once when the
+    /// bucket is full.
+    pub fn new(per_second: NonZeroU32) -> Self {
+        let per_second = f64::from(per_second.get());
+        Self {
+            per_second,
+            burst: per_second,
+            state: Mutex::new(State {
+                tokens: per_second,
+                last_refill: Instant::now(),
+            }),
+            on_delay: None,
+        }
+    }
+
+    /// Allows `burst` requests at once when the bucket is full, instead of the
+    /// number of requests per second.
+    pub fn with_burst(mut self, burst: NonZeroU32) -> Self {
+        self.burst = f64::from(burst.get());
+        let state = self.state.get_mut().unwrap_or_else(|err| err.into_inner());
+        state.tokens = self.burst;
+        self
+    }
+
+    /// Calls `callback` with the delay of every request that has to wait, to
+    /// monitor the throttling and size the number of requests per second.
+    pub fn with_delay_callback<F: Fn(Duration) + Send + Sync + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.on_delay = Some(Arc::new(callback));
+        self
+    }
+
+    /// Remaining budget, and how long the next request would wait, without
+    /// taking any token.
+    pub fn status(&self) -> RateLimitStatus {
+        self.status_at(Instant::now())
+    }
+
+    fn status_at(&self, now: Instant) -> RateLimitStatus {
+        let state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        let tokens = self.refilled_tokens(&state, now);
+        RateLimitStatus {
+            remaining: tokens.max(0.0).floor() as u32,
+            wait: Duration::from_secs_f64((1.0 - tokens).max(0.0) / self.per_second),
+        }
+    }
+
+    /// Takes a token for a request, returning how long it has to wait before
+    /// being sent.
+    pub(crate) fn reserve(&self) -> Option<Duration> {
+        let delay = self.reserve_at(Instant::now());
+        if let (Some(delay), Some(callback)) = (delay, self.on_delay.as_ref()) {
+            callback(delay);
+        }
+        delay
+    }
+
+    fn refilled_tokens(&self, state: &State, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(state.last_refill);
+        (state.tokens + elapsed.as_secs_f64() * self.per_second).min(self.burst)
+    }
+
+    fn reserve_at(&self, now: Instant) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        state.tokens = self.refilled_tokens(&state, now);
+        state.last_refill = now;
+        state.tokens -= 1.0;
+        if state.tokens >= 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(-state.tokens / self.per_second))
+        }
+    }
+}
+
+/// Waits for the given duration on the timer thread, for the runtimes without
+/// a timer exposed to the executor.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    let Some(deadline) = Instant::now().checked_add(duration) else {
+        return futures_util::future::pending().await;
+    };
+    let id = {
+        let mut state = TIMER.lock();
+        state.next_id += 1;
+        state.next_id
+    };
+    futures_util::future::poll_fn(|cx| {
+        if Instant::now() >= deadline {
+            return std::task::Poll::Ready(());
+        }
+        match TIMER.register((deadline, id), cx.waker().clone()) {
+            Ok(()) => std::task::Poll::Pending,
+            Err(err) => {
+                log::warn!("couldn't wait for the rate limiter: {err}");
+                std::task::Poll::Ready(())
+            }
+        }
+    })
+    .await
+}
+
+/// Single thread waking the tasks waiting with [sleep] once their deadline is
+/// reached, whatever the number of tasks waiting.
+#[cfg(not(target_arch = "wasm32"))]
+struct Timer {
+    state: Mutex<TimerState>,
+    changed: std::sync::Condvar,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct TimerState {
+    /// Wakers by deadline, the id keeping apart the tasks with the same one.
+    wakers: std::collections::BTreeMap<(Instant, u64), std::task::Waker>,
+    next_id: u64,
+    started: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+static TIMER: Timer = Timer {
+    state: Mutex::new(TimerState {
+        wakers: std::collections::BTreeMap::new(),
+        next_id: 0,
+        started: false,
+    }),
+    changed: std::sync::Condvar::new(),
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Timer {
+    fn lock(&self) -> std::sync::MutexGuard<'_, TimerState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Wakes the task once the deadline is reached, starting the thread of the
+    /// timer if needed.
+    fn register(
+        &'static self,
+        key: (Instant, u64),
+        waker: std::task::Waker,
+    ) -> std::io::Result<()> {
+        let mut state = self.lock();
+        if !state.started {
+            std::thread::Builder::new()
+                .name("tmdb-api-timer".into())
+                .spawn(move || self.run())?;
+            state.started = true;
+        }
+        state.wakers.insert(key, waker);
+        self.changed.notify_one();
+        Ok(())
+    }
+
+    fn run(&self) {
+        let mut state = self.lock();
+        loop {
+            let now = Instant::now();
+            while let Some(entry) = state.wakers.first_entry() {
+                if entry.key().0 > now {
+                    break;
+                }
+                entry.remove().wake();
+            }
+            state = match state.wakers.keys().next() {
+                Some((deadline, _)) => {
+                    let timeout = deadline.saturating_duration_since(now);
+                    self.changed
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(|err| err.into_inner())
+                        .0
+                }
+                None => self
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(|err| err.into_inner()),
+            };
+        }
+    }
+}
+
+/// Clock and timer of the JavaScript environment, the ones of the standard
+/// library panicking on `wasm32`.
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use std::time::Duration;
+
+    /// Milliseconds since the epoch, enough to refill the bucket or measure a
+    /// latency.
+    #[derive(Clone, Copy, Debug)]
+    pub(crate) struct Instant(f64);
+
+    impl Instant {
+        pub(crate) fn now() -> Self {
+            Self(js_sys::Date::now())
+        }
+
+        pub(crate) fn saturating_duration_since(&self, earlier: Self) -> Duration {
+            Duration::from_secs_f64((self.0 - earlier.0).max(0.0) / 1000.0)
+        }
+    }
+
+    /// Waits for the given duration with `setTimeout`.
+    pub(crate) async fn sleep(duration: Duration) {
+        let millis = duration.as_millis().min(i32::MAX as u128) as f64;
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            let global = js_sys::global();
+            let scheduled = js_sys::Reflect::get(&global, &js_sys::JsString::from("setTimeout"))
+                .map(js_sys::Function::from)
+                .and_then(|set_timeout| {
+                    set_timeout.call2(&global, &resolve, &js_sys::Number::from(millis))
+                });
+            if scheduled.is_err() {
+                let _ = resolve.call0(&global);
+            }
+        });
+        let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use super::RateLimiter;
+
+    #[test]
+    fn should_delay_requests_over_the_limit() {
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap());
+        let now = Instant::now();
+        assert_eq!(limiter.reserve_at(now), None);
+        assert_eq!(limiter.reserve_at(now), None);
+        assert_eq!(limiter.reserve_at(now), Some(Duration::from_millis(500)));
+        assert_eq!(limiter.reserve_at(now), Some(Duration::from_secs(1)));
+        // the tokens are refilled over time
+        let later = now + Duration::from_secs(2);
+        assert_eq!(limiter.reserve_at(later), None);
+    }
+
+    #[test]
+    fn should_limit_burst() {
+        let limiter =
+            RateLimiter::new(NonZeroU32::new(4).unwrap()).with_burst(NonZeroU32::new(1).unwrap());
+        let now = Instant::now();
+        assert_eq!(limiter.reserve_at(now), None);
+        assert_eq!(limiter.reserve_at(now), Some(Duration::from_millis(250)));
+        // the bucket never holds more than the burst
+        let later = now + Duration::from_secs(10);
+        assert_eq!(limiter.reserve_at(later), None);
+        assert_eq!(limiter.reserve_at(later), Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn should_report_status() {
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap());
+        let now = Instant::now();
+        let status = limiter.status_at(now);
+        assert_eq!(status.remaining, 2);
+        assert_eq!(status.wait, Duration::ZERO);
+        limiter.reserve_at(now);
+        limiter.reserve_at(now);
+        let status = limiter.status_at(now);
+        assert_eq!(status.remaining, 0);
+        assert_eq!(status.wait, Duration::from_millis(500));
+        // reading the status doesn't take any token
+        assert_eq!(limiter.status_at(now), status);
+        let later = now + Duration::from_millis(500);
+        assert_eq!(limiter.status_at(later).remaining, 1);
+    }
+
+    #[test]
+    fn should_call_delay_callback() {
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let limiter = RateLimiter::new(NonZeroU32::new(1).unwrap()).with_delay_callback({
+            let delays = delays.clone();
+            move |delay| delays.lock().unwrap().push(delay)
+        });
+        assert_eq!(limiter.reserve(), None);
+        let delay = limiter.reserve().unwrap();
+        assert_eq!(*delays.lock().unwrap(), vec![delay]);
+    }
+
+    #[tokio::test]
+    async fn should_sleep() {
+        let start = Instant::now();
+        super::sleep(Duration::from_millis(50)).await;
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn should_sleep_concurrently() {
+        let start = Instant::now();
+        let tasks = (1..=100).map(|index| super::sleep(Duration::from_millis(index % 5 * 10)));
+        futures_util::future::join_all(tasks).await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(40));
+        assert!(elapsed < Duration::from_millis(500), "{elapsed:?}");
+    }
+}
@@ -0,0 +1,318 @@
+//! Executor recording the responses of another executor to disk and replaying
+//! them afterwards, making the integration tests deterministic and offline.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use serde_json::value::RawValue;
+
+use super::Method;
+
+/// Whether a [ReplayExecutor] sends the requests or reads the recorded
+/// responses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReplayMode {
+    /// Replays the recorded responses, recording the missing ones.
+    #[default]
+    Auto,
+    /// Always sends the requests, recording their responses.
+    Record,
+    /// Only replays the recorded responses, failing on the missing ones.
+    Replay,
+}
+
+/// No response was recorded for the request.
+#[derive(Debug, thiserror::Error)]
+#[error("no recorded response for {method} {key}, expected in {path:?}")]
+pub struct MissingRecord {
+    pub method: &'static str,
+    /// Url of the request, without the api key.
+    pub key: String,
+    pub path: PathBuf,
+}
+
+/// Response written to disk. The request isn't written, to keep its secrets
+/// out of the records.
+#[derive(Debug, Deserialize, Serialize)]
+struct Record<'a> {
+    status_code: u16,
+    #[serde(borrow)]
+    body: &'a RawValue,
+}
+
+/// Executor wrapping another one, recording its responses in a directory
+/// and replaying them afterwards.
+///
+/// The records are named after the method, the path and a hash of the
+/// request, ignoring the api key and the access token so that they can be
+/// shared.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::replay::{ReplayExecutor, ReplayMode};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// let executor = ReplayExecutor::new(ReqwestExecutor::default(), "tests/records")
+///     .with_mode(ReplayMode::Auto);
+/// let client = Client::builder()
+///     .with_api_key("this-is-my-secret-token".into())
+///     .with_executor(executor)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ReplayExecutor<E> {
+    inner: E,
+    directory: PathBuf,
+    mode: ReplayMode,
+}
+
+/// Records in the `records` directory.
+impl<E: Default> Default for ReplayExecutor<E> {
+    fn default() -> Self {
+        Self::new(E::default(), "records")
+    }
+}
+
+impl<E> ReplayExecutor<E> {
+    pub fn new<P: Into<PathBuf>>(inner: E, directory: P) -> Self {
+        Self {
+            inner,
+            directory: directory.into(),
+            mode: ReplayMode::default(),
+        }
+    }
+
+    pub fn with_mode(mut self, mode: ReplayMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn set_mode(&mut self, mode: ReplayMode) {
+        self.mode = mode;
+    }
+
+    /// The wrapped executor.
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn record_path(&self, method: Method, key: &str, body: Option<&str>) -> PathBuf {
+        let path = super::url_path(key);
+        let mut name = String::with_capacity(path.len() + 32);
+        name.push_str(&method.as_str().to_ascii_lowercase());
+        for part in path.split('/').filter(|part| !part.is_empty()) {
+            name.push('-');
+            name.extend(
+                part.chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .take(32),
+            );
+        }
+        let hash = fnv1a([key, body.unwrap_or_default()]);
+        self.directory.join(format!("{name}-{hash:016x}.json"))
+    }
+}
+
+/// Hash that stays the same between the versions of Rust, unlike the one of
+/// the standard library.
+fn fnv1a<'a>(parts: impl IntoIterator<Item = &'a str>) -> u64 {
+    parts
+        .into_iter()
+        .flat_map(|part| part.bytes().chain(std::iter::once(0)))
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        })
+}
+
+fn io_error(err: impl std::error::Error + Send + 'static) -> crate::error::Error {
+    crate::error::Error::Request {
+        source: Box::new(err),
+    }
+}
+
+impl<E: super::Executor> ReplayExecutor<E> {
+    async fn send<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<T, crate::error::Error> {
+        let key = super::cache::cache_key(url);
+        let path = self.record_path(method, &key, body.as_deref());
+
+        if self.mode != ReplayMode::Record {
+            match std::fs::read(&path) {
+                Ok(content) => {
+                    let record: Record<'_> = serde_json::from_slice(&content).map_err(io_error)?;
+                    let body = bytes::Bytes::from(record.body.get().to_string());
+                    return super::handle_response(url, record.status_code, body);
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    if self.mode == ReplayMode::Replay {
+                        return Err(io_error(MissingRecord {
+                            method: method.as_str(),
+                            key: key.to_string(),
+                            path,
+                        }));
+                    }
+                }
+                Err(err) => return Err(io_error(err)),
+            }
+        }
+
+        let (status_code, body) = match self
+            .inner
+            .execute_request::<Box<RawValue>>(method, url, body, access_token)
+            .await
+        {
+            Ok(body) => (200, body.get().to_string()),
+            Err(crate::error::Error::Validation(content)) => {
+                (422, serde_json::to_string(&content).map_err(io_error)?)
+            }
+            Err(crate::error::Error::Server { code, content }) => {
+                (code, serde_json::to_string(&content).map_err(io_error)?)
+            }
+            Err(err) => return Err(err),
+        };
+        let record = Record {
+            status_code,
+            body: serde_json::from_str(&body).map_err(io_error)?,
+        };
+        std::fs::create_dir_all(&self.directory).map_err(io_error)?;
+        let content = serde_json::to_vec_pretty(&record).map_err(io_error)?;
+        std::fs::write(&path, content).map_err(io_error)?;
+        super::handle_response(url, status_code, bytes::Bytes::from(body))
+    }
+}
+
+#[async_trait::async_trait]
+impl<E: super::Executor> super::prelude::Executor for ReplayExecutor<E> {
+    async fn execute<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<T, crate::error::Error> {
+        let url = super::append_params(url, &params);
+        self.send(Method::Get, &url, None, None).await
+    }
+
+    async fn execute_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<T, crate::error::Error> {
+        self.send(method, url, body, access_token).await
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        self.inner.sleep(duration).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{Client, Method};
+    use crate::movie::details::MovieDetails;
+    use crate::prelude::Command;
+
+    use super::{ReplayExecutor, ReplayMode};
+
+    #[test]
+    fn should_name_records() {
+        let executor = ReplayExecutor::new(ReqwestExecutor::default(), "records");
+        let path = executor.record_path(
+            Method::Get,
+            "http://127.0.0.1:1234/3/movie/550?language=fr-FR",
+            None,
+        );
+        assert_eq!(
+            path.to_str().unwrap(),
+            "records/get-3-movie-550-3db44bd6f74e3f2f.json"
+        );
+        assert_ne!(
+            path,
+            executor.record_path(Method::Get, "http://127.0.0.1:1234/3/movie/550", None)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_record_and_replay() {
+        let directory = std::env::temp_dir().join(format!(
+            "tmdb-api-replay-{}-{}",
+            std::process::id(),
+            line!()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        let mut server = mockito::Server::new_async().await;
+        let details = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .expect(1)
+            .create_async()
+            .await;
+        let missing = server
+            .mock("GET", "/movie/1")
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(ReplayExecutor::new(
+                ReqwestExecutor::default(),
+                directory.clone(),
+            ))
+            .build()
+            .unwrap();
+        for _ in 0..2 {
+            let result = MovieDetails::new(550).execute(&client).await.unwrap();
+            assert_eq!(result.inner.id, 550);
+            let err = MovieDetails::new(1).execute(&client).await.unwrap_err();
+            assert_eq!(err.as_server_error().unwrap().status_code, 34);
+        }
+        details.assert_async().await;
+        missing.assert_async().await;
+
+        // the records don't contain the api key
+        for entry in std::fs::read_dir(&directory).unwrap() {
+            let content = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            assert!(!content.contains("secret"));
+        }
+
+        let client = Client::builder()
+            .with_api_key("other".into())
+            .with_base_url(server.url())
+            .with_executor(
+                ReplayExecutor::new(ReqwestExecutor::default(), directory.clone())
+                    .with_mode(ReplayMode::Replay),
+            )
+            .build()
+            .unwrap();
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+        let err = MovieDetails::new(2).execute(&client).await.unwrap_err();
+        assert!(err.to_string().contains("couldn't execute request"));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
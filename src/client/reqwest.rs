@@ -1,13 +1,248 @@
 use std::borrow::Cow;
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
 
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, ETAG, IF_NONE_MATCH};
+
+use super::cache::lru::Lru;
+
+/// Executor based on [reqwest].
+///
+/// An already configured [reqwest::Client] can be reused with
+/// `ReqwestExecutor::from(client)`, or the most common settings can be tuned
+/// with the [ReqwestExecutorBuilder].
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// let executor = ReqwestExecutor::builder()
+///     .with_pool_max_idle_per_host(8)
+///     .with_tcp_keepalive(Some(Duration::from_secs(60)))
+///     .build()
+///     .unwrap();
+/// let client = Client::builder()
+///     .with_api_key("this-is-my-secret-token".into())
+///     .with_executor(executor)
+///     .build()
+///     .unwrap();
+/// ```
 #[derive(Debug, Default)]
 pub struct ReqwestExecutor {
     inner: reqwest::Client,
+    etag_cache: Option<EtagCache>,
+}
+
+/// Number of bodies kept by the `ETag` cache enabled with
+/// [ReqwestExecutor::with_etag_cache].
+pub const DEFAULT_ETAG_CACHE_CAPACITY: NonZeroUsize = match NonZeroUsize::new(1000) {
+    Some(value) => value,
+    None => unreachable!(),
+};
+
+/// Bodies of the `GET` responses with an `ETag`, keyed by url and
+/// authorization with [etag_key], so that TMDB can answer `304 Not Modified`
+/// without sending them again. The least recently used ones are dropped once
+/// full, like in the [crate::client::cache::memory::MemoryCache].
+type EtagCache = Lru<(HeaderValue, bytes::Bytes)>;
+
+fn etag_key(url: &str, authorization: Option<&HeaderValue>) -> String {
+    match authorization {
+        Some(value) => format!("{url} {}", String::from_utf8_lossy(value.as_bytes())),
+        None => url.to_string(),
+    }
+}
+
+impl ReqwestExecutor {
+    pub fn builder() -> ReqwestExecutorBuilder {
+        ReqwestExecutorBuilder::default()
+    }
+
+    /// The underlying reqwest client.
+    pub fn inner(&self) -> &reqwest::Client {
+        &self.inner
+    }
+
+    /// Keeps the bodies of the responses having an `ETag` and sends it back in
+    /// the `If-None-Match` header, returning the kept body when TMDB answers
+    /// `304 Not Modified`. At most [DEFAULT_ETAG_CACHE_CAPACITY] bodies are
+    /// kept, dropping the least recently used ones.
+    pub fn with_etag_cache(self, value: bool) -> Self {
+        self.with_etag_cache_capacity(value.then_some(DEFAULT_ETAG_CACHE_CAPACITY))
+    }
+
+    /// Same as [ReqwestExecutor::with_etag_cache], keeping at most the given
+    /// number of bodies. `None` disables the cache.
+    pub fn with_etag_cache_capacity(mut self, capacity: Option<NonZeroUsize>) -> Self {
+        self.etag_cache = capacity.map(Lru::new);
+        self
+    }
+
+    /// Removes the bodies kept by the `ETag` cache, if enabled.
+    pub fn clear_etag_cache(&self) {
+        if let Some(ref cache) = self.etag_cache {
+            cache.clear();
+        }
+    }
+
+    /// Sends a request, going through the `ETag` cache for the `GET` ones,
+    /// and reads its response.
+    async fn fetch(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<(reqwest::StatusCode, HeaderMap, bytes::Bytes), crate::error::Error> {
+        let mut req = req.build()?;
+        let cache = self
+            .etag_cache
+            .as_ref()
+            .filter(|_| req.method() == reqwest::Method::GET);
+        let Some(cache) = cache else {
+            let mut res = self.inner.execute(req).await?;
+            let status_code = res.status();
+            let headers = std::mem::take(res.headers_mut());
+            let body = res.bytes().await.map_err(response_error)?;
+            return Ok((status_code, headers, body));
+        };
+
+        let key = etag_key(req.url().as_str(), req.headers().get(AUTHORIZATION));
+        let cached = cache.get_at(&key, Instant::now());
+        if let Some((ref etag, _)) = cached {
+            req.headers_mut().insert(IF_NONE_MATCH, etag.clone());
+        }
+        let mut res = self.inner.execute(req).await?;
+        let status_code = res.status();
+        let headers = std::mem::take(res.headers_mut());
+        if status_code == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some((_, body)) = cached {
+                return Ok((reqwest::StatusCode::OK, headers, body));
+            }
+        }
+        let etag = headers.get(ETAG).cloned();
+        let body = res.bytes().await.map_err(response_error)?;
+        if status_code.is_success() {
+            match etag {
+                Some(etag) => cache.put_at(&key, (etag, body.clone()), None),
+                None => cache.remove(&key),
+            };
+        }
+        Ok((status_code, headers, body))
+    }
+
+    fn request(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> reqwest::RequestBuilder {
+        let mut req = self.inner.request(request_method(method), url);
+        if let Some(access_token) = access_token {
+            req = req.bearer_auth(access_token);
+        }
+        if let Some(body) = body {
+            req = req
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    "application/json;charset=utf-8",
+                )
+                .body(body);
+        }
+        req
+    }
 }
 
 impl From<reqwest::Client> for ReqwestExecutor {
     fn from(inner: reqwest::Client) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            etag_cache: None,
+        }
+    }
+}
+
+impl TryFrom<reqwest::ClientBuilder> for ReqwestExecutor {
+    type Error = reqwest::Error;
+
+    fn try_from(builder: reqwest::ClientBuilder) -> Result<Self, Self::Error> {
+        builder.build().map(Self::from)
+    }
+}
+
+/// Builder of a [ReqwestExecutor], passing the settings through to the
+/// [reqwest::ClientBuilder].
+#[derive(Debug, Default)]
+pub struct ReqwestExecutorBuilder {
+    inner: reqwest::ClientBuilder,
+    etag_cache: Option<NonZeroUsize>,
+}
+
+impl From<reqwest::ClientBuilder> for ReqwestExecutorBuilder {
+    fn from(inner: reqwest::ClientBuilder) -> Self {
+        Self {
+            inner,
+            etag_cache: None,
+        }
+    }
+}
+
+impl ReqwestExecutorBuilder {
+    /// Maximum number of idle connections kept per host.
+    pub fn with_pool_max_idle_per_host(mut self, value: usize) -> Self {
+        self.inner = self.inner.pool_max_idle_per_host(value);
+        self
+    }
+
+    /// Timeout for idle connections to be kept alive in the pool.
+    pub fn with_pool_idle_timeout(mut self, value: Option<Duration>) -> Self {
+        self.inner = self.inner.pool_idle_timeout(value);
+        self
+    }
+
+    /// Only use HTTP/2.
+    pub fn with_http2_prior_knowledge(mut self) -> Self {
+        self.inner = self.inner.http2_prior_knowledge();
+        self
+    }
+
+    /// Interval of the TCP keep-alive probes.
+    pub fn with_tcp_keepalive(mut self, value: Option<Duration>) -> Self {
+        self.inner = self.inner.tcp_keepalive(value);
+        self
+    }
+
+    /// Timeout of a request, from connecting until the response body is read.
+    pub fn with_timeout(mut self, value: Duration) -> Self {
+        self.inner = self.inner.timeout(value);
+        self
+    }
+
+    /// Timeout of the connection phase.
+    pub fn with_connect_timeout(mut self, value: Duration) -> Self {
+        self.inner = self.inner.connect_timeout(value);
+        self
+    }
+
+    pub fn with_user_agent(mut self, value: &str) -> Self {
+        self.inner = self.inner.user_agent(value);
+        self
+    }
+
+    /// See [ReqwestExecutor::with_etag_cache].
+    pub fn with_etag_cache(mut self, value: bool) -> Self {
+        self.etag_cache = value.then_some(DEFAULT_ETAG_CACHE_CAPACITY);
+        self
+    }
+
+    /// See [ReqwestExecutor::with_etag_cache_capacity].
+    pub fn with_etag_cache_capacity(mut self, capacity: Option<NonZeroUsize>) -> Self {
+        self.etag_cache = capacity;
+        self
+    }
+
+    pub fn build(self) -> Result<ReqwestExecutor, reqwest::Error> {
+        ReqwestExecutor::try_from(self.inner)
+            .map(|executor| executor.with_etag_cache_capacity(self.etag_cache))
     }
 }
 
@@ -19,6 +254,31 @@ impl From<reqwest::Error> for crate::error::Error {
     }
 }
 
+fn request_method(method: super::Method) -> reqwest::Method {
+    match method {
+        super::Method::Get => reqwest::Method::GET,
+        super::Method::Post => reqwest::Method::POST,
+        super::Method::Put => reqwest::Method::PUT,
+        super::Method::Delete => reqwest::Method::DELETE,
+    }
+}
+
+fn response_error(err: impl std::error::Error + Send + 'static) -> crate::error::Error {
+    crate::error::Error::Response {
+        source: Box::new(err),
+    }
+}
+
+/// Builds the result of a response, from the url of its request, its status
+/// code and its body.
+fn handle<T: serde::de::DeserializeOwned>(
+    url: &str,
+    status_code: reqwest::StatusCode,
+    body: bytes::Bytes,
+) -> Result<T, crate::error::Error> {
+    super::handle_response(url, status_code.as_u16(), body)
+}
+
 #[async_trait::async_trait]
 impl super::prelude::Executor for ReqwestExecutor {
     async fn execute<T: serde::de::DeserializeOwned>(
@@ -26,44 +286,249 @@ impl super::prelude::Executor for ReqwestExecutor {
         url: &str,
         params: Vec<(&str, Cow<'_, str>)>,
     ) -> Result<T, crate::error::Error> {
-        let res = self
-            .inner
-            .get(url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|err| crate::error::Error::Request {
-                source: Box::new(err),
-            })?;
+        let (status_code, _, body) = self.fetch(self.inner.get(url).query(&params)).await?;
+        handle(url, status_code, body)
+    }
 
-        let status_code = res.status();
-        if status_code.is_success() {
-            res.json::<T>()
-                .await
-                .map_err(|err| crate::error::Error::Response {
-                    source: Box::new(err),
-                })
-        } else if status_code == reqwest::StatusCode::UNPROCESSABLE_ENTITY {
-            let payload: crate::error::ServerValidationBodyError =
-                res.json()
-                    .await
-                    .map_err(|err| crate::error::Error::Response {
-                        source: Box::new(err),
-                    })?;
-            Err(crate::error::Error::Validation(payload))
-        } else {
-            let content: crate::error::ServerOtherBodyError =
-                res.json()
-                    .await
-                    .map_err(|err| crate::error::Error::Response {
-                        source: Box::new(err),
-                    })?;
-            Err(crate::error::Error::Server {
-                code: status_code.as_u16(),
-                content,
-            })
+    async fn execute_logged<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+        body_limit: usize,
+    ) -> Result<T, crate::error::Error> {
+        let (status_code, _, body) = self.fetch(self.inner.get(url).query(&params)).await?;
+        log::debug!(
+            "response {status_code}: {}",
+            super::truncate_body(&String::from_utf8_lossy(&body), body_limit)
+        );
+        handle(url, status_code, body)
+    }
+
+    async fn execute_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<T, crate::error::Error> {
+        let req = self.request(method, url, body, access_token);
+        let (status_code, _, body) = self.fetch(req).await?;
+        handle(url, status_code, body)
+    }
+
+    /// Waits with the timer of tokio, the runtime used by reqwest, outside of
+    /// `wasm32`.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await
+    }
+
+    async fn execute_with_meta<T: serde::de::DeserializeOwned>(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+    ) -> Result<super::Response<T>, crate::error::Error> {
+        let req = self.request(method, url, body, access_token);
+        let (status_code, headers, body) = self.fetch(req).await?;
+        Ok(super::Response {
+            status_code: status_code.as_u16(),
+            headers: super::response_headers(
+                headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_bytes())),
+            ),
+            body: handle(url, status_code, body)?,
+        })
+    }
+
+    async fn execute_with_meta_logged<T: serde::de::DeserializeOwned>(
+        &self,
+        method: super::Method,
+        url: &str,
+        body: Option<String>,
+        access_token: Option<&str>,
+        body_limit: usize,
+    ) -> Result<super::Response<T>, crate::error::Error> {
+        let req = self.request(method, url, body, access_token);
+        let (status_code, headers, body) = self.fetch(req).await?;
+        log::debug!(
+            "response {status_code}: {}",
+            super::truncate_body(&String::from_utf8_lossy(&body), body_limit)
+        );
+        Ok(super::Response {
+            status_code: status_code.as_u16(),
+            headers: super::response_headers(
+                headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_bytes())),
+            ),
+            body: handle(url, status_code, body)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mockito::Matcher;
+
+    use crate::client::Client;
+    use crate::movie::details::MovieDetails;
+    use crate::prelude::Command;
+
+    use super::ReqwestExecutor;
+
+    #[tokio::test]
+    async fn should_keep_undecodable_body() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(502)
+            .with_header("content-type", "text/html")
+            .with_body("<html>Bad Gateway</html>")
+            .create_async()
+            .await;
+
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        let err = MovieDetails::new(550).execute(&client).await.unwrap_err();
+        assert_eq!(err.raw_body(), Some("<html>Bad Gateway</html>".as_bytes()));
+        assert_eq!(
+            err.to_string(),
+            "couldn't decode response body of /movie/550 with status 502: <html>Bad Gateway</html>"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_use_configured_client() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("user-agent", "my-service/1.0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let executor = ReqwestExecutor::builder()
+            .with_pool_max_idle_per_host(2)
+            .with_pool_idle_timeout(Some(Duration::from_secs(30)))
+            .with_tcp_keepalive(Some(Duration::from_secs(60)))
+            .with_timeout(Duration::from_secs(5))
+            .with_user_agent("my-service/1.0")
+            .build()
+            .unwrap();
+        let client = Client::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(executor)
+            .build()
+            .unwrap();
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+
+        let reqwest_client = reqwest::Client::builder()
+            .user_agent("my-service/1.0")
+            .build()
+            .unwrap();
+        let client = Client::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(ReqwestExecutor::from(reqwest_client))
+            .build()
+            .unwrap();
+        let result = MovieDetails::new(550).execute(&client).await.unwrap();
+        assert_eq!(result.inner.id, 550);
+    }
+
+    #[tokio::test]
+    async fn should_use_etag_cache() {
+        let mut server = mockito::Server::new_async().await;
+        let fresh = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("if-none-match", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("etag", "\"abcd\"")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .expect(1)
+            .create_async()
+            .await;
+        let not_modified = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("if-none-match", "\"abcd\"")
+            .with_status(304)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(
+                ReqwestExecutor::builder()
+                    .with_etag_cache(true)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        for _ in 0..3 {
+            let result = MovieDetails::new(550).execute(&client).await.unwrap();
+            assert_eq!(result.inner.id, 550);
         }
+        fresh.assert_async().await;
+        not_modified.assert_async().await;
+    }
 
-        // todo!()
+    #[test]
+    fn should_key_etag_cache_by_authorization() {
+        let token = reqwest::header::HeaderValue::from_static("Bearer token");
+        assert_eq!(super::etag_key("/a", None), "/a");
+        assert_ne!(
+            super::etag_key("/a", Some(&token)),
+            super::etag_key("/a", None)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_metadata() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("etag", "\"abcd\"")
+            .with_header("Cache-Control", "public, max-age=28800")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .create_async()
+            .await;
+
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        let result = client
+            .execute_command_with_meta(&MovieDetails::new(550))
+            .await
+            .unwrap();
+        assert_eq!(result.status_code, 200);
+        assert_eq!(result.etag(), Some("\"abcd\""));
+        assert_eq!(result.cache_control(), Some("public, max-age=28800"));
+        assert_eq!(result.body.inner.id, 550);
     }
 }
@@ -1,6 +1,11 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
-use crate::common::MediaType;
+use crate::common::{MediaType, ShortString};
+use crate::movie::{MovieBase, MovieShort};
+use crate::prelude::Command;
+
+const PATH: &str = "/collection/{collection_id}";
 
 /// Command to get the details of a collection
 ///
@@ -29,23 +34,32 @@ pub struct CollectionDetails {
     pub language: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CollectionDetailsResult {
     #[serde(flatten)]
     pub inner: super::CollectionBase,
     pub parts: Vec<Media>,
+    /// Fields of the response that aren't supported yet by this crate.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Media {
     pub id: u64,
     pub media_type: MediaType,
-    pub title: String,
-    pub original_language: String,
-    pub original_title: String,
+    pub title: ShortString,
+    pub original_language: ShortString,
+    pub original_title: ShortString,
     pub overview: String,
-    pub poster_path: Option<String>,
-    pub backdrop_path: Option<String>,
+    pub poster_path: Option<ShortString>,
+    pub backdrop_path: Option<ShortString>,
     #[serde(default)]
     pub genre_ids: Vec<u64>,
     #[serde(default)]
@@ -62,6 +76,75 @@ pub struct Media {
     pub release_date: Option<chrono::NaiveDate>,
 }
 
+/// Converts a part of a collection to the type used by the movie lists.
+impl From<Media> for MovieShort {
+    fn from(value: Media) -> Self {
+        Self {
+            inner: MovieBase {
+                id: value.id,
+                title: value.title,
+                original_title: value.original_title,
+                original_language: value.original_language,
+                overview: value.overview,
+                release_date: value.release_date,
+                poster_path: value.poster_path,
+                backdrop_path: value.backdrop_path,
+                adult: value.adult,
+                popularity: value.popularity,
+                vote_count: value.vote_count,
+                vote_average: value.vote_average,
+                video: value.video,
+            },
+            genre_ids: value.genre_ids,
+            media_type: Some(value.media_type),
+            #[cfg(feature = "strict")]
+            unknown_fields: Default::default(),
+        }
+    }
+}
+
+impl CollectionDetailsResult {
+    /// Parts of the collection that are movies, as used by the movie lists.
+    pub fn movies(&self) -> impl Iterator<Item = MovieShort> + '_ {
+        self.parts
+            .iter()
+            .filter(|item| matches!(item.media_type, MediaType::Movie))
+            .cloned()
+            .map(MovieShort::from)
+    }
+
+    /// Parts of the collection sorted by release date, the ones without a
+    /// release date coming last.
+    pub fn parts_by_release_date(&self) -> Vec<&Media> {
+        let mut parts: Vec<&Media> = self.parts.iter().collect();
+        parts.sort_by_key(|item| (item.release_date.is_none(), item.release_date));
+        parts
+    }
+}
+
+impl super::CollectionBase {
+    /// Fetches the details of the collection, with its parts.
+    pub async fn details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<CollectionDetailsResult, crate::error::Error> {
+        CollectionDetails::new(self.id).execute(client).await
+    }
+}
+
+impl crate::movie::Movie {
+    /// Fetches the details of the collection the movie belongs to, if any.
+    pub async fn belongs_to_collection_details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<Option<CollectionDetailsResult>, crate::error::Error> {
+        match self.belongs_to_collection {
+            Some(ref collection) => collection.details(client).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
 impl CollectionDetails {
     pub fn new(collection_id: u64) -> Self {
         Self {
@@ -76,11 +159,21 @@ impl CollectionDetails {
     }
 }
 
-impl crate::prelude::Command for CollectionDetails {
+impl Command for CollectionDetails {
     type Output = CollectionDetailsResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/collection/{}", self.collection_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/collection/{}", self.collection_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -92,7 +185,7 @@ impl crate::prelude::Command for CollectionDetails {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -122,6 +215,63 @@ mod tests {
 
         let result = CollectionDetails::new(10).execute(&client).await.unwrap();
         assert_eq!(result.inner.id, 10);
+        let movies: Vec<_> = result.movies().collect();
+        assert_eq!(movies.len(), result.parts.len());
+        assert_eq!(movies[0].inner.id, result.parts[0].id);
+        assert_eq!(movies[0].inner.title, result.parts[0].title);
+        assert_eq!(movies[0].genre_ids, result.parts[0].genre_ids);
+    }
+
+    #[test]
+    fn should_sort_parts_by_release_date() {
+        let mut result: super::CollectionDetailsResult =
+            serde_json::from_str(include_str!("../../assets/collection-details.json")).unwrap();
+        result.parts[0].release_date = None;
+        let parts = result.parts_by_release_date();
+        assert_eq!(parts.len(), result.parts.len());
+        assert_eq!(parts.last().unwrap().id, result.parts[0].id);
+        assert!(parts[..parts.len() - 1]
+            .windows(2)
+            .all(|pair| pair[0].release_date <= pair[1].release_date));
+        assert_eq!(
+            parts[7].release_date,
+            chrono::NaiveDate::from_ymd_opt(2019, 12, 18)
+        );
+    }
+
+    #[tokio::test]
+    async fn belongs_to_collection_details_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/collection/10")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/collection-details.json"))
+            .create_async()
+            .await;
+
+        let mut movie: crate::movie::Movie =
+            serde_json::from_str(include_str!("../../assets/movie-details.json")).unwrap();
+        movie.belongs_to_collection = None;
+        let result = movie.belongs_to_collection_details(&client).await.unwrap();
+        assert!(result.is_none());
+
+        movie.belongs_to_collection = Some(crate::collection::CollectionBase {
+            id: 10,
+            name: "Star Wars Collection".into(),
+            overview: None,
+            poster_path: None,
+            backdrop_path: None,
+        });
+        let result = movie.belongs_to_collection_details(&client).await.unwrap();
+        assert_eq!(result.unwrap().inner.id, 10);
     }
 
     #[tokio::test]
@@ -1,7 +1,10 @@
 #[cfg(feature = "commands")]
 pub mod details;
+#[cfg(feature = "commands")]
+pub mod translations;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CollectionBase {
     pub id: u64,
     pub name: String,
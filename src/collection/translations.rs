@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::translation::TranslationsResult;
+
+const PATH: &str = "/collection/{collection_id}/translations";
+
+/// Get a list of translations that have been created for a collection.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::collection::translations::CollectionTranslations;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = CollectionTranslations::new(1);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CollectionTranslations {
+    /// ID of the collection.
+    pub collection_id: u64,
+}
+
+impl CollectionTranslations {
+    pub fn new(collection_id: u64) -> Self {
+        Self { collection_id }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TranslationData {
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub overview: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub homepage: Option<String>,
+}
+
+pub type Translation = crate::common::translation::Translation<TranslationData>;
+pub type CollectionTranslationsResult = TranslationsResult<TranslationData>;
+
+impl crate::prelude::Command for CollectionTranslations {
+    type Output = CollectionTranslationsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/collection/{}/translations", self.collection_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::CollectionTranslations;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/collection/10/translations")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/collection-translations.json"))
+            .create_async()
+            .await;
+
+        let result = CollectionTranslations::new(10)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 10);
+        assert!(!result.translations.is_empty());
+        let german = result.find("de", Some("DE")).unwrap();
+        assert_eq!(german.data.overview, None);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/collection/10/translations")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = CollectionTranslations::new(10)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/collection/10/translations")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = CollectionTranslations::new(10)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use super::CollectionTranslations;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = CollectionTranslations::new(10)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 10);
+    }
+}
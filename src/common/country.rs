@@ -1,5 +1,83 @@
+use std::fmt::Display;
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Country {
     pub iso_3166_1: String,
     pub name: String,
 }
+
+/// ISO 3166-1 code of a country, like `US`, always stored in uppercase.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub struct CountryCode(String);
+
+impl CountryCode {
+    pub fn new<V: AsRef<str>>(value: V) -> Self {
+        Self(value.as_ref().to_uppercase())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for CountryCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for CountryCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for CountryCode {
+    fn from(value: String) -> Self {
+        if value.bytes().any(|c| c.is_ascii_lowercase()) {
+            Self(value.to_uppercase())
+        } else {
+            Self(value)
+        }
+    }
+}
+
+impl From<&str> for CountryCode {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<CountryCode> for String {
+    fn from(value: CountryCode) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<str> for CountryCode {
+    fn eq(&self, other: &str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for CountryCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CountryCode;
+
+    #[test]
+    fn should_normalize_country_code() {
+        let code: CountryCode = serde_json::from_str("\"fr\"").unwrap();
+        assert_eq!(code.as_str(), "FR");
+        assert_eq!(code, "fr");
+        assert_eq!(code, CountryCode::from("Fr"));
+        assert_eq!(serde_json::to_string(&code).unwrap(), "\"FR\"");
+    }
+}
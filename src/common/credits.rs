@@ -1,26 +1,34 @@
 use crate::people::PersonShort;
 
-#[derive(Debug, Deserialize, Serialize)]
+/// Fields shared by every credited person. The `credit_id` is available on the
+/// flattened [PersonShort] for plain credits and on each role or job for
+/// aggregate credits.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct CreditCommon {
-    pub credit_id: String,
     pub adult: bool,
     pub known_for_department: Option<String>,
     pub original_name: String,
     pub popularity: f64,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Cast {
     #[serde(flatten)]
     pub credit: CreditCommon,
     #[serde(flatten)]
     pub person: PersonShort,
-    pub cast_id: u64,
+    /// Only provided for movies.
+    #[serde(default)]
+    pub cast_id: Option<u64>,
     pub character: String,
     pub order: u64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Crew {
     #[serde(flatten)]
     pub credit: CreditCommon,
@@ -28,4 +36,78 @@ pub struct Crew {
     pub person: PersonShort,
     pub department: String,
     pub job: String,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CastRole {
+    pub credit_id: String,
+    pub character: String,
+    pub episode_count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AggregateCast {
+    #[serde(flatten)]
+    pub credit: CreditCommon,
+    #[serde(flatten)]
+    pub person: PersonShort,
+    pub roles: Vec<CastRole>,
+    pub total_episode_count: u64,
+    pub order: u64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CrewJob {
+    pub credit_id: String,
+    pub job: String,
+    pub episode_count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AggregateCrew {
+    #[serde(flatten)]
+    pub credit: CreditCommon,
+    #[serde(flatten)]
+    pub person: PersonShort,
+    pub jobs: Vec<CrewJob>,
+    pub department: String,
+    pub total_episode_count: u64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cast, Crew};
+
+    #[test]
+    fn should_keep_all_fields() {
+        let input = r#"{"adult":false,"gender":2,"id":819,"known_for_department":"Acting","name":"Edward Norton","original_name":"Edward Norton","popularity":19.575,"profile_path":"/5XBzD5WuTyVQZeS4VI25z2moMeY.jpg","cast_id":4,"character":"Narrator","credit_id":"52fe4250c3a36847f80149f3","order":0}"#;
+        let cast: Cast = serde_json::from_str(input).unwrap();
+        assert_eq!(
+            cast.person.credit_id.as_deref(),
+            Some("52fe4250c3a36847f80149f3")
+        );
+        assert_eq!(cast.cast_id, Some(4));
+        let output = serde_json::to_string(&cast).unwrap();
+        assert_eq!(output.matches("credit_id").count(), 1);
+        assert_eq!(serde_json::from_str::<Cast>(&output).unwrap(), cast);
+
+        let input = r#"{"department":"Directing","job":"Director","credit_id":"5256c8a219c2956ff6046e77","adult":false,"gender":2,"id":44797,"known_for_department":"Directing","name":"Timothy Van Patten","original_name":"Timothy Van Patten","popularity":21.102,"profile_path":null}"#;
+        let crew: Crew = serde_json::from_str(input).unwrap();
+        assert_eq!(crew.job, "Director");
+        assert_eq!(crew.credit.original_name, "Timothy Van Patten");
+    }
 }
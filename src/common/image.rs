@@ -1,4 +1,5 @@
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Image {
     pub aspect_ratio: f64,
     pub file_path: String,
@@ -1,4 +1,5 @@
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Keyword {
     pub id: u64,
     pub name: String,
@@ -1,5 +1,9 @@
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Language {
     pub iso_639_1: String,
     pub name: String,
+    /// Not provided by all the endpoints.
+    #[serde(default)]
+    pub english_name: Option<String>,
 }
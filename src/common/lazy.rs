@@ -0,0 +1,78 @@
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::value::RawValue;
+
+/// Part of a response kept as raw JSON and only parsed on demand, to save the
+/// parsing and the allocations when only the other fields are needed.
+///
+/// The raw JSON can't be kept when the parent type uses `#[serde(flatten)]`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Lazy<T> {
+    raw: Box<RawValue>,
+    #[serde(skip)]
+    kind: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        Self {
+            raw: self.raw.clone(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for Lazy<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw.get() == other.raw.get()
+    }
+}
+
+impl<T> Lazy<T> {
+    /// Raw JSON of the value.
+    pub fn raw(&self) -> &str {
+        self.raw.get()
+    }
+}
+
+impl<T: DeserializeOwned> Lazy<T> {
+    pub fn parse(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(self.raw.get())
+    }
+}
+
+impl<T: Serialize> Lazy<T> {
+    pub fn from_value(value: &T) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            raw: serde_json::value::to_raw_value(value)?,
+            kind: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lazy;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Parent {
+        id: u64,
+        items: Lazy<Vec<u64>>,
+    }
+
+    #[test]
+    fn should_parse_on_demand() {
+        let parent: Parent = serde_json::from_str(r#"{"id":1,"items":[1, 2,3]}"#).unwrap();
+        assert_eq!(parent.id, 1);
+        assert_eq!(parent.items.raw(), "[1, 2,3]");
+        assert_eq!(parent.items.parse().unwrap(), vec![1, 2, 3]);
+        assert_eq!(
+            serde_json::to_string(&parent).unwrap(),
+            r#"{"id":1,"items":[1, 2,3]}"#
+        );
+        assert_eq!(Lazy::from_value(&vec![1u64, 2]).unwrap().raw(), "[1,2]");
+    }
+}
@@ -0,0 +1,16 @@
+/// Public list a movie or a TV show belongs to.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ListSummary {
+    pub id: u64,
+    pub name: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub list_type: String,
+    pub poster_path: Option<String>,
+    pub iso_639_1: String,
+    #[serde(default)]
+    pub iso_3166_1: Option<String>,
+    pub item_count: u64,
+    pub favorite_count: u64,
+}
@@ -5,19 +5,57 @@ pub mod credits;
 pub mod image;
 pub mod keyword;
 pub mod language;
+pub mod lazy;
+pub mod list;
+pub mod rating;
 pub mod release_date;
+pub mod score;
+mod short_string;
 pub mod status;
+#[cfg(feature = "strict")]
+pub mod strict;
+pub mod translation;
 pub mod video;
 
+pub use self::short_string::ShortString;
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PaginatedResult<T> {
     pub page: u64,
     pub total_results: u64,
     pub total_pages: u64,
     pub results: Vec<T>,
+    /// ID of the resource listed, only provided by the endpoints listing the
+    /// items of a single resource, like the reviews of a movie.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Range of the release dates, only provided by the upcoming movies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dates: Option<DateRange>,
+}
+
+/// Range of dates, like the release dates of the movies playing now.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DateRange {
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub maximum: Option<chrono::NaiveDate>,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub minimum: Option<chrono::NaiveDate>,
+}
+
+/// Status returned by the commands writing data, like rating a TV show.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StatusResult {
+    #[serde(default)]
+    pub success: bool,
+    pub status_code: u16,
+    pub status_message: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum MediaType {
     Movie,
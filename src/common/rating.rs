@@ -0,0 +1,68 @@
+/// Rating given by a user, from `0.5` to `10.0` by steps of `0.5`.
+///
+/// ```rust
+/// use tmdb_api::common::rating::Rating;
+///
+/// assert_eq!(Rating::new(8.5).unwrap().value(), 8.5);
+/// assert!(Rating::new(0.0).is_err());
+/// assert!(Rating::new(7.3).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(try_from = "f32", into = "f32")]
+pub struct Rating(f32);
+
+#[derive(Clone, Copy, Debug, PartialEq, thiserror::Error)]
+#[error("invalid rating {0}, expected a value from 0.5 to 10.0 by steps of 0.5")]
+pub struct InvalidRating(pub f32);
+
+impl Rating {
+    pub const MIN: f32 = 0.5;
+    pub const MAX: f32 = 10.0;
+
+    pub fn new(value: f32) -> Result<Self, InvalidRating> {
+        if (Self::MIN..=Self::MAX).contains(&value) && (value * 2.0).fract() == 0.0 {
+            Ok(Self(value))
+        } else {
+            Err(InvalidRating(value))
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl TryFrom<f32> for Rating {
+    type Error = InvalidRating;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Rating> for f32 {
+    fn from(value: Rating) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rating;
+
+    #[test]
+    fn should_validate_rating() {
+        for value in [0.5, 1.0, 5.5, 10.0] {
+            assert_eq!(Rating::new(value).unwrap().value(), value);
+        }
+        for value in [-1.0, 0.0, 0.25, 9.9, 10.5, f32::NAN, f32::INFINITY] {
+            assert!(Rating::new(value).is_err(), "{value} should be invalid");
+        }
+        assert!(serde_json::from_str::<Rating>("7.5").is_ok());
+        assert!(serde_json::from_str::<Rating>("11").is_err());
+        assert_eq!(
+            serde_json::to_string(&Rating::new(7.5).unwrap()).unwrap(),
+            "7.5"
+        );
+    }
+}
@@ -1,10 +1,11 @@
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct LocatedReleaseDates {
     pub iso_3166_1: String,
     pub release_dates: Vec<ReleaseDate>,
 }
 
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug)]
+#[derive(Clone, Serialize_repr, Deserialize_repr, PartialEq, Debug)]
 #[repr(u8)]
 pub enum ReleaseDateKind {
     Premiere = 1,
@@ -15,7 +16,8 @@ pub enum ReleaseDateKind {
     TV = 6,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ReleaseDate {
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub certification: Option<String>,
@@ -26,4 +28,7 @@ pub struct ReleaseDate {
     pub release_date: chrono::DateTime<chrono::Utc>,
     #[serde(rename = "type")]
     pub kind: ReleaseDateKind,
+    /// Content descriptors of the certification, like violence.
+    #[serde(default)]
+    pub descriptors: Vec<String>,
 }
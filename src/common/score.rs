@@ -0,0 +1,95 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Totally ordered wrapper for the floating scores, like `vote_average` or
+/// `popularity`, so they can be sorted, used as keys of a `BTreeMap` or in types
+/// deriving `Eq` and `Hash`.
+///
+/// The values are compared with [f64::total_cmp].
+///
+/// ```rust
+/// use tmdb_api::common::score::OrderedScore;
+///
+/// let mut scores = vec![OrderedScore(7.5), OrderedScore(8.4), OrderedScore(6.1)];
+/// scores.sort();
+/// assert_eq!(scores, vec![OrderedScore(6.1), OrderedScore(7.5), OrderedScore(8.4)]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct OrderedScore(pub f64);
+
+impl OrderedScore {
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl From<f64> for OrderedScore {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OrderedScore> for f64 {
+    fn from(value: OrderedScore) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq for OrderedScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedScore {}
+
+impl PartialOrd for OrderedScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedScore {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for OrderedScore {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // consistent with total_cmp, that only considers equal identical bits
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl std::fmt::Display for OrderedScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::OrderedScore;
+
+    #[test]
+    fn should_order_and_deduplicate() {
+        let values = [8.4, 6.1, 8.4, 7.5];
+        let set: BTreeSet<OrderedScore> = values.into_iter().map(OrderedScore).collect();
+        let sorted: Vec<f64> = set.into_iter().map(f64::from).collect();
+        assert_eq!(sorted, vec![6.1, 7.5, 8.4]);
+        let set: HashSet<OrderedScore> = values.into_iter().map(OrderedScore).collect();
+        assert_eq!(set.len(), 3);
+        assert!(OrderedScore(f64::NAN) > OrderedScore(10.0));
+    }
+
+    #[test]
+    fn should_be_transparent() {
+        let score: OrderedScore = serde_json::from_str("7.5").unwrap();
+        assert_eq!(score.value(), 7.5);
+        assert_eq!(serde_json::to_string(&score).unwrap(), "7.5");
+    }
+}
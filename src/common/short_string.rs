@@ -0,0 +1,134 @@
+/// String used by the short fields of the types returned in lists, like the names,
+/// the language codes or the image paths.
+///
+/// It's a plain `String` by default. With the `compact-strings` feature, it's a
+/// wrapper around an `Arc<str>`, that doesn't keep any extra capacity and is cheap
+/// to clone, to reduce the memory used when holding lots of results.
+#[cfg(not(feature = "compact-strings"))]
+pub type ShortString = String;
+
+#[cfg(feature = "compact-strings")]
+pub use self::compact::ShortString;
+
+#[cfg(feature = "compact-strings")]
+mod compact {
+    use std::borrow::Borrow;
+    use std::fmt;
+    use std::ops::Deref;
+    use std::sync::Arc;
+
+    /// String stored as an `Arc<str>`, dereferencing to `str` and converting
+    /// from and into a `String`.
+    #[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct ShortString(Arc<str>);
+
+    impl ShortString {
+        pub fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl Deref for ShortString {
+        type Target = str;
+
+        fn deref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl AsRef<str> for ShortString {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl Borrow<str> for ShortString {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl fmt::Debug for ShortString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.as_str(), f)
+        }
+    }
+
+    impl fmt::Display for ShortString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl From<String> for ShortString {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+
+    impl From<&str> for ShortString {
+        fn from(value: &str) -> Self {
+            Self(value.into())
+        }
+    }
+
+    impl From<ShortString> for String {
+        fn from(value: ShortString) -> Self {
+            value.0.to_string()
+        }
+    }
+
+    impl PartialEq<str> for ShortString {
+        fn eq(&self, other: &str) -> bool {
+            self.as_str() == other
+        }
+    }
+
+    impl PartialEq<&str> for ShortString {
+        fn eq(&self, other: &&str) -> bool {
+            self.as_str() == *other
+        }
+    }
+
+    impl PartialEq<String> for ShortString {
+        fn eq(&self, other: &String) -> bool {
+            self.as_str() == other
+        }
+    }
+
+    impl serde::Serialize for ShortString {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for ShortString {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            String::deserialize(deserializer).map(Self::from)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShortString;
+
+    #[test]
+    fn should_behave_like_a_string() {
+        let value: ShortString = serde_json::from_str(r#""Fight Club""#).unwrap();
+        assert_eq!(value, "Fight Club");
+        assert_eq!(value.len(), 10);
+        assert!(value.starts_with("Fight"));
+        assert_eq!(value.to_string(), "Fight Club");
+        assert_eq!(value.as_str(), "Fight Club");
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""Fight Club""#);
+    }
+
+    #[cfg(feature = "compact-strings")]
+    #[test]
+    fn should_convert_into_a_string() {
+        let value = ShortString::from("Fight Club");
+        assert_eq!(String::from(value.clone()), "Fight Club");
+        assert_eq!(value, String::from("Fight Club"));
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, Error, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Type flattened next to a [DenyUnknownFields], that rejects the unknown
+/// fields itself.
+///
+/// Serde only consumes the fields of the flattened types without any
+/// flattened field, so the [DenyUnknownFields] of a type flattening
+/// [crate::movie::MovieShort] still sees the fields of the movie and has to
+/// accept them.
+pub trait KnownFields {
+    /// Names of the fields of the type, as sent by TMDB.
+    const FIELDS: &'static [&'static str];
+}
+
+/// For the types only flattening types without any flattened field.
+impl KnownFields for () {
+    const FIELDS: &'static [&'static str] = &[];
+}
+
+/// Rejects the fields left by the other fields of a type using
+/// `#[serde(flatten)]`, given that serde doesn't support `deny_unknown_fields`
+/// on them. It has to be flattened after the other fields.
+///
+/// The types flattening another type that rejects the unknown fields itself,
+/// like [crate::account::RatedMovie], give it as parameter, to accept its
+/// fields.
+pub struct DenyUnknownFields<T = ()>(PhantomData<fn() -> T>);
+
+impl<T> Clone for DenyUnknownFields<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DenyUnknownFields<T> {}
+
+impl<T> fmt::Debug for DenyUnknownFields<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DenyUnknownFields")
+    }
+}
+
+impl<T> Default for DenyUnknownFields<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> PartialEq for DenyUnknownFields<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for DenyUnknownFields<T> {}
+
+impl<'de, T: KnownFields> Deserialize<'de> for DenyUnknownFields<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DenyVisitor<T>(PhantomData<fn() -> T>);
+
+        impl<'de, T: KnownFields> Visitor<'de> for DenyVisitor<T> {
+            type Value = DenyUnknownFields<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("no unknown field")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                while let Some(key) = map.next_key::<String>()? {
+                    if !T::FIELDS.contains(&key.as_str()) {
+                        return Err(A::Error::unknown_field(&key, &[]));
+                    }
+                    map.next_value::<IgnoredAny>()?;
+                }
+                Ok(DenyUnknownFields::default())
+            }
+        }
+
+        deserializer.deserialize_map(DenyVisitor(PhantomData))
+    }
+}
+
+/// Flattened, it doesn't write any field.
+impl<T> Serialize for DenyUnknownFields<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenyUnknownFields;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Inner {
+        id: u64,
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Outer {
+        #[serde(flatten)]
+        inner: Inner,
+        name: String,
+        #[serde(flatten)]
+        unknown_fields: DenyUnknownFields,
+    }
+
+    #[test]
+    fn should_accept_known_fields() {
+        let value: Outer = serde_json::from_str(r#"{"id":1,"name":"foo"}"#).unwrap();
+        assert_eq!(value.inner.id, 1);
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#"{"id":1,"name":"foo"}"#
+        );
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Middle {
+        #[serde(flatten)]
+        inner: Inner,
+        #[serde(flatten)]
+        unknown_fields: DenyUnknownFields,
+    }
+
+    impl super::KnownFields for Middle {
+        const FIELDS: &'static [&'static str] = &["id"];
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Nested {
+        #[serde(flatten)]
+        middle: Middle,
+        name: String,
+        #[serde(flatten)]
+        unknown_fields: DenyUnknownFields<Middle>,
+    }
+
+    #[test]
+    fn should_accept_known_fields_of_nested_type() {
+        let value: Nested = serde_json::from_str(r#"{"id":1,"name":"foo"}"#).unwrap();
+        assert_eq!(value.middle.inner.id, 1);
+        let err =
+            serde_json::from_str::<Nested>(r#"{"id":1,"name":"foo","bar":true}"#).unwrap_err();
+        assert!(err.to_string().contains("unknown field `bar`"), "{err}");
+    }
+
+    #[test]
+    fn should_reject_unknown_fields() {
+        let err = serde_json::from_str::<Outer>(r#"{"id":1,"name":"foo","bar":true}"#).unwrap_err();
+        assert!(err.to_string().contains("unknown field `bar`"), "{err}");
+    }
+}
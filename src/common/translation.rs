@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+/// Translation of a resource in a given language, `T` being the translated
+/// fields that depend on the kind of resource.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Translation<T> {
+    pub iso_3166_1: String,
+    pub iso_639_1: String,
+    pub name: String,
+    pub english_name: String,
+    pub data: T,
+}
+
+impl<T> Translation<T> {
+    /// Language tag of the translation, like `fr-FR`.
+    pub fn tag(&self) -> String {
+        format!("{}-{}", self.iso_639_1, self.iso_3166_1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TranslationsResult<T> {
+    pub id: u64,
+    pub translations: Vec<Translation<T>>,
+}
+
+impl<T> TranslationsResult<T> {
+    /// Finds the translation matching the given ISO 639-1 language and,
+    /// when provided, ISO 3166-1 country.
+    pub fn find(&self, language: &str, country: Option<&str>) -> Option<&Translation<T>> {
+        self.translations.iter().find(|item| {
+            item.iso_639_1 == language
+                && (country.is_none() || country == Some(item.iso_3166_1.as_str()))
+        })
+    }
+
+    /// Finds the translation matching a language tag like `fr-FR`, or `fr` to
+    /// accept any country.
+    pub fn get(&self, tag: &str) -> Option<&Translation<T>> {
+        match tag.split_once('-') {
+            Some((language, country)) => self.find(language, Some(country)),
+            None => self.find(tag, None),
+        }
+    }
+
+    /// Indexes the translations by ISO 639-1 language and ISO 3166-1 country, for
+    /// repeated lookups.
+    pub fn to_map(&self) -> HashMap<(&str, &str), &Translation<T>> {
+        self.translations
+            .iter()
+            .map(|item| ((item.iso_639_1.as_str(), item.iso_3166_1.as_str()), item))
+            .collect()
+    }
+
+    /// Converts the translations into a map keyed by ISO 639-1 language and
+    /// ISO 3166-1 country.
+    pub fn into_map(self) -> HashMap<(String, String), Translation<T>> {
+        self.translations
+            .into_iter()
+            .map(|item| ((item.iso_639_1.clone(), item.iso_3166_1.clone()), item))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TranslationsResult;
+
+    #[derive(Debug, Deserialize)]
+    struct Data {
+        title: String,
+    }
+
+    #[test]
+    fn should_find_translation() {
+        let result: TranslationsResult<Data> = serde_json::from_str(
+            r#"{"id":1,"translations":[
+                {"iso_3166_1":"FR","iso_639_1":"fr","name":"Français","english_name":"French","data":{"title":"France"}},
+                {"iso_3166_1":"CA","iso_639_1":"fr","name":"Français","english_name":"French","data":{"title":"Canada"}}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(result.find("fr", None).unwrap().data.title, "France");
+        assert_eq!(result.find("fr", Some("CA")).unwrap().data.title, "Canada");
+        assert!(result.find("de", None).is_none());
+
+        assert_eq!(result.get("fr").unwrap().data.title, "France");
+        assert_eq!(result.get("fr-CA").unwrap().data.title, "Canada");
+        assert_eq!(result.get("fr-CA").unwrap().tag(), "fr-CA");
+        assert!(result.get("fr-BE").is_none());
+
+        assert_eq!(result.to_map()[&("fr", "CA")].data.title, "Canada");
+        let map = result.into_map();
+        assert_eq!(map.len(), 2);
+        assert_eq!(
+            map[&("fr".to_string(), "FR".to_string())].data.title,
+            "France"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Video {
     pub id: String,
     pub name: String,
@@ -10,4 +11,6 @@ pub struct Video {
     pub size: u64,
     pub iso_639_1: String,
     pub iso_3166_1: String,
+    #[serde(default)]
+    pub official: bool,
 }
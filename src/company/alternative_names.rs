@@ -1,4 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+const PATH: &str = "/company/{company_id}/alternative_names";
 
 /// Command to get details of a company
 ///
@@ -31,7 +34,8 @@ impl CompanyAlternativeNames {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyAlternativeName {
     pub name: String,
     #[serde(
@@ -41,7 +45,8 @@ pub struct CompanyAlternativeName {
     pub kind: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyAlternativeNamesResult {
     pub id: u64,
     pub results: Vec<CompanyAlternativeName>,
@@ -51,7 +56,17 @@ impl crate::prelude::Command for CompanyAlternativeNames {
     type Output = CompanyAlternativeNamesResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/company/{}/alternative_names", self.company_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/company/{}/alternative_names", self.company_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -59,7 +74,7 @@ impl crate::prelude::Command for CompanyAlternativeNames {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::CompanyAlternativeNames;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,4 +1,9 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::prelude::Command;
+
+const PATH: &str = "/company/{company_id}";
 
 /// Command to get details of a company
 ///
@@ -31,11 +36,31 @@ impl CompanyDetails {
     }
 }
 
-impl crate::prelude::Command for CompanyDetails {
+impl super::CompanyShort {
+    /// Fetches the details of the company.
+    pub async fn details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<super::Company, crate::error::Error> {
+        CompanyDetails::new(self.id).execute(client).await
+    }
+}
+
+impl Command for CompanyDetails {
     type Output = super::Company;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/company/{}", self.company_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/company/{}", self.company_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -43,7 +68,7 @@ impl crate::prelude::Command for CompanyDetails {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::CompanyDetails;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,4 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+const PATH: &str = "/company/{company_id}/images";
 
 /// Command to get images of a company
 ///
@@ -31,7 +34,8 @@ impl CompanyImages {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyImage {
     pub aspect_ratio: f64,
     pub file_path: String,
@@ -43,7 +47,8 @@ pub struct CompanyImage {
     pub vote_count: u64,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyImagesResult {
     pub id: u64,
     pub logos: Vec<CompanyImage>,
@@ -53,7 +58,17 @@ impl crate::prelude::Command for CompanyImages {
     type Output = CompanyImagesResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/company/{}/images", self.company_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/company/{}/images", self.company_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -61,7 +76,7 @@ impl crate::prelude::Command for CompanyImages {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::CompanyImages;
     use crate::client::reqwest::ReqwestExecutor;
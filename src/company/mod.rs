@@ -6,6 +6,7 @@ pub mod details;
 pub mod images;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyShort {
     pub id: u64,
     pub name: String,
@@ -23,4 +24,12 @@ pub struct Company {
     pub headquarters: String,
     pub homepage: String,
     pub parent_company: Option<CompanyShort>,
+    /// Fields of the response that aren't supported yet by this crate.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
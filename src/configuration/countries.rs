@@ -2,6 +2,8 @@
 
 use std::borrow::Cow;
 
+const PATH: &str = "/configuration/countries";
+
 /// Get a list of all countries
 ///
 /// ```rust
@@ -25,7 +27,8 @@ pub struct Countries {
     language: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CountriesResult {
     pub iso_3166_1: String,
     pub english_name: String,
@@ -47,7 +50,11 @@ impl crate::prelude::Command for Countries {
     type Output = Vec<CountriesResult>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/configuration/countries")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -59,7 +66,7 @@ impl crate::prelude::Command for Countries {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -0,0 +1,162 @@
+//! https://developer.themoviedb.org/reference/configuration-details
+
+use std::borrow::Cow;
+
+use super::ImagesConfiguration;
+
+const PATH: &str = "/configuration";
+
+/// Get the image configuration and the change keys
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::configuration::details::ConfigurationDetails;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let result = ConfigurationDetails::new().execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {res:#?}"),
+///         Err(err) => eprintln!("error: {err:?}"),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ConfigurationDetails;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ConfigurationDetailsResult {
+    pub images: ImagesConfiguration,
+    #[serde(default)]
+    pub change_keys: Vec<String>,
+}
+
+impl ConfigurationDetails {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl crate::prelude::Command for ConfigurationDetails {
+    type Output = ConfigurationDetailsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::prelude::Command;
+    use crate::Client;
+
+    use super::ConfigurationDetails;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/configuration-details.json"))
+            .create_async()
+            .await;
+
+        let result = ConfigurationDetails::new().execute(&client).await.unwrap();
+        assert_eq!(result.images.secure_base_url, "https://image.tmdb.org/t/p/");
+        assert!(!result.change_keys.is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = ConfigurationDetails::new()
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ConfigurationDetails::new()
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::prelude::Command;
+    use crate::Client;
+
+    use super::ConfigurationDetails;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = ConfigurationDetails::new().execute(&client).await.unwrap();
+        assert!(!result.images.poster_sizes.is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+//! Build the urls of the images from their paths.
+
+use super::ImagesConfiguration;
+
+/// Builds the urls of the images, like the `poster_path` of a movie.
+///
+/// By default, it uses a compiled-in snapshot of the image configuration of TMDB,
+/// [ImageUrlBuilder::load] fetches the live one and falls back on the snapshot
+/// when it fails.
+///
+/// ```rust
+/// use tmdb_api::configuration::image_url::ImageUrlBuilder;
+///
+/// let builder = ImageUrlBuilder::default();
+/// assert_eq!(
+///     builder.url("w500", "/pB8BM7pdSp6B6Ih7QZ4DrQ3PmJK.jpg"),
+///     "https://image.tmdb.org/t/p/w500/pB8BM7pdSp6B6Ih7QZ4DrQ3PmJK.jpg",
+/// );
+///
+/// let builder = ImageUrlBuilder::default().with_base_url(Some("https://cdn.example.com/tmdb".into()));
+/// assert_eq!(
+///     builder.original("/pB8BM7pdSp6B6Ih7QZ4DrQ3PmJK.jpg"),
+///     "https://cdn.example.com/tmdb/original/pB8BM7pdSp6B6Ih7QZ4DrQ3PmJK.jpg",
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ImageUrlBuilder {
+    configuration: ImagesConfiguration,
+    base_url: Option<String>,
+}
+
+impl From<ImagesConfiguration> for ImageUrlBuilder {
+    fn from(configuration: ImagesConfiguration) -> Self {
+        Self {
+            configuration,
+            base_url: None,
+        }
+    }
+}
+
+impl ImageUrlBuilder {
+    /// Overrides the host of the images, ignoring the one of the configuration.
+    pub fn with_base_url(mut self, value: Option<String>) -> Self {
+        self.base_url = value;
+        self
+    }
+
+    pub fn set_base_url(&mut self, value: Option<String>) {
+        self.base_url = value;
+    }
+
+    pub fn configuration(&self) -> &ImagesConfiguration {
+        &self.configuration
+    }
+
+    /// Base url of the images, without trailing slash.
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(&self.configuration.secure_base_url)
+            .trim_end_matches('/')
+    }
+
+    /// Url of the image at the given size, like `w500`.
+    pub fn url(&self, size: &str, path: &str) -> String {
+        format!(
+            "{}/{}/{}",
+            self.base_url(),
+            size,
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// Url of the image in its original size.
+    pub fn original(&self, path: &str) -> String {
+        self.url("original", path)
+    }
+}
+
+#[cfg(feature = "commands")]
+impl ImageUrlBuilder {
+    /// Builds with the live configuration of TMDB, or the compiled-in snapshot
+    /// when it can't be fetched.
+    pub async fn load<E: crate::client::Executor>(client: &crate::Client<E>) -> Self {
+        use crate::prelude::Command;
+
+        match super::details::ConfigurationDetails::new()
+            .execute(client)
+            .await
+        {
+            Ok(res) => Self::from(res.images),
+            Err(err) => {
+                log::warn!("couldn't fetch the image configuration, using the fallback: {err}");
+                Self::default()
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::Client;
+
+    use super::ImageUrlBuilder;
+
+    #[tokio::test]
+    async fn load_should_fallback() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"change_keys":[],"images":{"base_url":"http://img.example.com/","secure_base_url":"https://img.example.com/","poster_sizes":["w92"]}}"#,
+            )
+            .create_async()
+            .await;
+        let builder = ImageUrlBuilder::load(&client).await;
+        assert_eq!(
+            builder.url("w92", "/a.jpg"),
+            "https://img.example.com/w92/a.jpg"
+        );
+
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("wrong".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+        let builder = ImageUrlBuilder::load(&client).await;
+        assert_eq!(
+            builder.url("w92", "/a.jpg"),
+            "https://image.tmdb.org/t/p/w92/a.jpg"
+        );
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::borrow::Cow;
 
+const PATH: &str = "/configuration/jobs";
+
 /// Get a list of all jobs
 ///
 /// ```rust
@@ -23,7 +25,8 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct Jobs {}
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct JobsResult {
     pub department: String,
     pub jobs: Vec<String>,
@@ -39,7 +42,11 @@ impl crate::prelude::Command for Jobs {
     type Output = Vec<JobsResult>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/configuration/jobs")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -47,7 +54,7 @@ impl crate::prelude::Command for Jobs {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
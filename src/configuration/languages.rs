@@ -2,6 +2,8 @@
 
 use std::borrow::Cow;
 
+const PATH: &str = "/configuration/languages";
+
 /// Get a list of all languages
 ///
 /// ```rust
@@ -23,7 +25,8 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct Languages {}
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct LanguagesResult {
     pub iso_639_1: String,
     pub english_name: String,
@@ -41,7 +44,11 @@ impl crate::prelude::Command for Languages {
     type Output = Vec<LanguagesResult>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/configuration/languages")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -49,7 +56,7 @@ impl crate::prelude::Command for Languages {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
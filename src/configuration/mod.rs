@@ -1,6 +1,71 @@
 #[cfg(feature = "commands")]
 pub mod countries;
 #[cfg(feature = "commands")]
+pub mod details;
+pub mod image_url;
+#[cfg(feature = "commands")]
 pub mod jobs;
 #[cfg(feature = "commands")]
 pub mod languages;
+
+/// Snapshot of the image configuration of TMDB, used when the live one isn't
+/// available.
+const FALLBACK_BASE_URL: &str = "http://image.tmdb.org/t/p/";
+const FALLBACK_SECURE_BASE_URL: &str = "https://image.tmdb.org/t/p/";
+const FALLBACK_BACKDROP_SIZES: &[&str] = &["w300", "w780", "w1280", "original"];
+const FALLBACK_LOGO_SIZES: &[&str] = &["w45", "w92", "w154", "w185", "w300", "w500", "original"];
+const FALLBACK_POSTER_SIZES: &[&str] = &["w92", "w154", "w185", "w342", "w500", "w780", "original"];
+const FALLBACK_PROFILE_SIZES: &[&str] = &["w45", "w185", "h632", "original"];
+const FALLBACK_STILL_SIZES: &[&str] = &["w92", "w185", "w300", "original"];
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+/// Where the images are hosted and in which sizes they're available.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ImagesConfiguration {
+    pub base_url: String,
+    pub secure_base_url: String,
+    #[serde(default)]
+    pub backdrop_sizes: Vec<String>,
+    #[serde(default)]
+    pub logo_sizes: Vec<String>,
+    #[serde(default)]
+    pub poster_sizes: Vec<String>,
+    #[serde(default)]
+    pub profile_sizes: Vec<String>,
+    #[serde(default)]
+    pub still_sizes: Vec<String>,
+}
+
+/// Compiled-in snapshot of the image configuration of TMDB.
+impl Default for ImagesConfiguration {
+    fn default() -> Self {
+        Self {
+            base_url: FALLBACK_BASE_URL.to_string(),
+            secure_base_url: FALLBACK_SECURE_BASE_URL.to_string(),
+            backdrop_sizes: to_strings(FALLBACK_BACKDROP_SIZES),
+            logo_sizes: to_strings(FALLBACK_LOGO_SIZES),
+            poster_sizes: to_strings(FALLBACK_POSTER_SIZES),
+            profile_sizes: to_strings(FALLBACK_PROFILE_SIZES),
+            still_sizes: to_strings(FALLBACK_STILL_SIZES),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn fallback_should_match_fixture() {
+        #[derive(Deserialize)]
+        struct Payload {
+            images: super::ImagesConfiguration,
+        }
+
+        let payload: Payload =
+            serde_json::from_str(include_str!("../../assets/configuration-details.json")).unwrap();
+        assert_eq!(payload.images, super::ImagesConfiguration::default());
+    }
+}
@@ -0,0 +1,197 @@
+//! Discover movies and TV shows with filters and sorting.
+
+#[cfg(feature = "commands")]
+pub mod movie;
+#[cfg(feature = "commands")]
+pub mod tvshow;
+
+use std::fmt::Display;
+
+/// Filter on a list of values, like genre or company IDs, matching either all
+/// of them (`AND`, comma separated) or any of them (`OR`, pipe separated).
+///
+/// ```rust
+/// use tmdb_api::discover::Filter;
+///
+/// assert_eq!(Filter::all([28, 12]).to_string(), "28,12");
+/// assert_eq!(Filter::any([28, 12]).to_string(), "28|12");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Filter<T> {
+    All(Vec<T>),
+    Any(Vec<T>),
+}
+
+impl<T> Filter<T> {
+    /// Matches the results having all the given values.
+    pub fn all<I: IntoIterator<Item = T>>(values: I) -> Self {
+        Self::All(values.into_iter().collect())
+    }
+
+    /// Matches the results having at least one of the given values.
+    pub fn any<I: IntoIterator<Item = T>>(values: I) -> Self {
+        Self::Any(values.into_iter().collect())
+    }
+
+    /// Matches the results having the given value.
+    pub fn one(value: T) -> Self {
+        Self::All(vec![value])
+    }
+
+    pub fn values(&self) -> &[T] {
+        match self {
+            Self::All(values) | Self::Any(values) => values,
+        }
+    }
+}
+
+impl<T: Display> Display for Filter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let separator = match self {
+            Self::All(_) => ",",
+            Self::Any(_) => "|",
+        };
+        for (index, value) in self.values().iter().enumerate() {
+            if index > 0 {
+                f.write_str(separator)?;
+            }
+            write!(f, "{value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Field to sort the discovered movies by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MovieSortField {
+    Popularity,
+    VoteAverage,
+    VoteCount,
+    PrimaryReleaseDate,
+    Revenue,
+    Title,
+    OriginalTitle,
+}
+
+impl MovieSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Popularity => "popularity",
+            Self::VoteAverage => "vote_average",
+            Self::VoteCount => "vote_count",
+            Self::PrimaryReleaseDate => "primary_release_date",
+            Self::Revenue => "revenue",
+            Self::Title => "title",
+            Self::OriginalTitle => "original_title",
+        }
+    }
+}
+
+impl Display for MovieSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Field to sort the discovered TV shows by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TVShowSortField {
+    Popularity,
+    VoteAverage,
+    VoteCount,
+    FirstAirDate,
+    Name,
+    OriginalName,
+}
+
+impl TVShowSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Popularity => "popularity",
+            Self::VoteAverage => "vote_average",
+            Self::VoteCount => "vote_count",
+            Self::FirstAirDate => "first_air_date",
+            Self::Name => "name",
+            Self::OriginalName => "original_name",
+        }
+    }
+}
+
+impl Display for TVShowSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Sorting of the discovered results, on a [MovieSortField] or a
+/// [TVShowSortField].
+///
+/// ```rust
+/// use tmdb_api::discover::{MovieSortField, SortBy};
+///
+/// assert_eq!(SortBy::desc(MovieSortField::Popularity).to_string(), "popularity.desc");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortBy<F> {
+    pub field: F,
+    pub descending: bool,
+}
+
+impl<F> SortBy<F> {
+    pub fn asc(field: F) -> Self {
+        Self {
+            field,
+            descending: false,
+        }
+    }
+
+    pub fn desc(field: F) -> Self {
+        Self {
+            field,
+            descending: true,
+        }
+    }
+}
+
+impl<F: Display> Display for SortBy<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let order = if self.descending { "desc" } else { "asc" };
+        write!(f, "{}.{}", self.field, order)
+    }
+}
+
+#[cfg(feature = "commands")]
+pub(crate) fn push_filter<'a, T: Display>(
+    res: &mut Vec<(&'static str, std::borrow::Cow<'a, str>)>,
+    key: &'static str,
+    value: Option<&Filter<T>>,
+) {
+    if let Some(value) = value.filter(|value| !value.values().is_empty()) {
+        res.push((key, std::borrow::Cow::Owned(value.to_string())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filter, MovieSortField, SortBy, TVShowSortField};
+
+    #[test]
+    fn should_format_filters() {
+        assert_eq!(Filter::all([28, 12, 16]).to_string(), "28,12,16");
+        assert_eq!(Filter::any(["fr", "de"]).to_string(), "fr|de");
+        assert_eq!(Filter::one(18).to_string(), "18");
+        assert_eq!(Filter::<u64>::all([]).to_string(), "");
+    }
+
+    #[test]
+    fn should_format_sort() {
+        assert_eq!(
+            SortBy::asc(MovieSortField::PrimaryReleaseDate).to_string(),
+            "primary_release_date.asc"
+        );
+        assert_eq!(
+            SortBy::desc(TVShowSortField::FirstAirDate).to_string(),
+            "first_air_date.desc"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! https://developer.themoviedb.org/reference/discover-movie
+
+use std::borrow::Cow;
+
+use chrono::NaiveDate;
+
+use super::{push_filter, Filter, MovieSortField, SortBy};
+
+const PATH: &str = "/discover/movie";
+
+/// Command to discover movies matching some filters
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::discover::{Filter, MovieSortField, SortBy};
+/// use tmdb_api::discover::movie::MovieDiscover;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = MovieDiscover::default()
+///         .with_genres(Some(Filter::all([28, 12])))
+///         .with_sort_by(Some(SortBy::desc(MovieSortField::Popularity)));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MovieDiscover {
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Which page to query.
+    pub page: Option<u32>,
+    /// Whether to include adult (pornography) content in the results.
+    pub include_adult: bool,
+    /// Whether to include the movies only available as videos.
+    pub include_video: bool,
+    /// ISO 3166-1 code to filter release region. Must be uppercase.
+    pub region: Option<String>,
+    pub sort_by: Option<SortBy<MovieSortField>>,
+    pub primary_release_year: Option<u16>,
+    pub primary_release_date_gte: Option<NaiveDate>,
+    pub primary_release_date_lte: Option<NaiveDate>,
+    pub vote_average_gte: Option<f32>,
+    pub vote_count_gte: Option<u64>,
+    pub with_genres: Option<Filter<u64>>,
+    pub without_genres: Option<Filter<u64>>,
+    pub with_companies: Option<Filter<u64>>,
+    pub with_keywords: Option<Filter<u64>>,
+    pub without_keywords: Option<Filter<u64>>,
+    pub with_cast: Option<Filter<u64>>,
+    pub with_crew: Option<Filter<u64>>,
+    pub with_people: Option<Filter<u64>>,
+    pub with_original_language: Option<String>,
+    pub with_watch_providers: Option<Filter<u64>>,
+    /// ISO 3166-1 code of the region of the watch providers.
+    pub watch_region: Option<String>,
+}
+
+impl MovieDiscover {
+    /// Discovers the movies produced by the given company, replacing the
+    /// deprecated `/company/{company_id}/movies` endpoint.
+    pub fn by_company(company_id: u64) -> Self {
+        Self::default().with_companies(Some(Filter::one(company_id)))
+    }
+
+    /// Discovers the movies tagged with the given keyword, replacing the
+    /// deprecated `/keyword/{keyword_id}/movies` endpoint.
+    pub fn by_keyword(keyword_id: u64) -> Self {
+        Self::default().with_keywords(Some(Filter::one(keyword_id)))
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_include_adult(mut self, value: bool) -> Self {
+        self.include_adult = value;
+        self
+    }
+
+    pub fn with_include_video(mut self, value: bool) -> Self {
+        self.include_video = value;
+        self
+    }
+
+    pub fn with_region(mut self, value: Option<String>) -> Self {
+        self.region = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy<MovieSortField>>) -> Self {
+        self.sort_by = value;
+        self
+    }
+
+    pub fn with_primary_release_year(mut self, value: Option<u16>) -> Self {
+        self.primary_release_year = value;
+        self
+    }
+
+    pub fn with_primary_release_date_gte(mut self, value: Option<NaiveDate>) -> Self {
+        self.primary_release_date_gte = value;
+        self
+    }
+
+    pub fn with_primary_release_date_lte(mut self, value: Option<NaiveDate>) -> Self {
+        self.primary_release_date_lte = value;
+        self
+    }
+
+    pub fn with_vote_average_gte(mut self, value: Option<f32>) -> Self {
+        self.vote_average_gte = value;
+        self
+    }
+
+    pub fn with_vote_count_gte(mut self, value: Option<u64>) -> Self {
+        self.vote_count_gte = value;
+        self
+    }
+
+    pub fn with_genres(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_genres = value;
+        self
+    }
+
+    pub fn without_genres(mut self, value: Option<Filter<u64>>) -> Self {
+        self.without_genres = value;
+        self
+    }
+
+    pub fn with_companies(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_companies = value;
+        self
+    }
+
+    pub fn with_keywords(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_keywords = value;
+        self
+    }
+
+    pub fn without_keywords(mut self, value: Option<Filter<u64>>) -> Self {
+        self.without_keywords = value;
+        self
+    }
+
+    pub fn with_cast(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_cast = value;
+        self
+    }
+
+    pub fn with_crew(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_crew = value;
+        self
+    }
+
+    pub fn with_people(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_people = value;
+        self
+    }
+
+    pub fn with_original_language(mut self, value: Option<String>) -> Self {
+        self.with_original_language = value;
+        self
+    }
+
+    pub fn with_watch_providers(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_watch_providers = value;
+        self
+    }
+
+    pub fn with_watch_region(mut self, value: Option<String>) -> Self {
+        self.watch_region = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for MovieDiscover {
+    type Output = crate::common::PaginatedResult<crate::movie::MovieShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+
+        if let Some(language) = self.language.as_ref() {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        if self.include_adult {
+            res.push(("include_adult", Cow::Borrowed("true")));
+        }
+        if self.include_video {
+            res.push(("include_video", Cow::Borrowed("true")));
+        }
+        if let Some(region) = self.region.as_ref() {
+            res.push(("region", Cow::Borrowed(region.as_str())));
+        }
+        if let Some(sort_by) = self.sort_by {
+            res.push(("sort_by", Cow::Owned(sort_by.to_string())));
+        }
+        if let Some(year) = self.primary_release_year {
+            res.push(("primary_release_year", Cow::Owned(year.to_string())));
+        }
+        if let Some(date) = self.primary_release_date_gte {
+            res.push(("primary_release_date.gte", Cow::Owned(date.to_string())));
+        }
+        if let Some(date) = self.primary_release_date_lte {
+            res.push(("primary_release_date.lte", Cow::Owned(date.to_string())));
+        }
+        if let Some(value) = self.vote_average_gte {
+            res.push(("vote_average.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.vote_count_gte {
+            res.push(("vote_count.gte", Cow::Owned(value.to_string())));
+        }
+        push_filter(&mut res, "with_genres", self.with_genres.as_ref());
+        push_filter(&mut res, "without_genres", self.without_genres.as_ref());
+        push_filter(&mut res, "with_companies", self.with_companies.as_ref());
+        push_filter(&mut res, "with_keywords", self.with_keywords.as_ref());
+        push_filter(&mut res, "without_keywords", self.without_keywords.as_ref());
+        push_filter(&mut res, "with_cast", self.with_cast.as_ref());
+        push_filter(&mut res, "with_crew", self.with_crew.as_ref());
+        push_filter(&mut res, "with_people", self.with_people.as_ref());
+        if let Some(language) = self.with_original_language.as_ref() {
+            res.push(("with_original_language", Cow::Borrowed(language.as_str())));
+        }
+        push_filter(
+            &mut res,
+            "with_watch_providers",
+            self.with_watch_providers.as_ref(),
+        );
+        if let Some(region) = self.watch_region.as_ref() {
+            res.push(("watch_region", Cow::Borrowed(region.as_str())));
+        }
+
+        res
+    }
+}
+
+impl crate::pagination::PaginatedCommand for MovieDiscover {
+    type Item = crate::movie::MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::discover::{Filter, MovieSortField, SortBy};
+    use crate::prelude::Command;
+
+    use super::MovieDiscover;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("sort_by".into(), "popularity.desc".into()),
+                Matcher::UrlEncoded("with_genres".into(), "28,12".into()),
+                Matcher::UrlEncoded("without_keywords".into(), "818|9715".into()),
+                Matcher::UrlEncoded("primary_release_date.gte".into(), "2020-01-01".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/discover-movie.json"))
+            .create_async()
+            .await;
+
+        let result = MovieDiscover::default()
+            .with_sort_by(Some(SortBy::desc(MovieSortField::Popularity)))
+            .with_genres(Some(Filter::all([28, 12])))
+            .without_keywords(Some(Filter::any([818, 9715])))
+            .with_primary_release_date_gte(chrono::NaiveDate::from_ymd_opt(2020, 1, 1))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert!(!result.results.is_empty());
+    }
+
+    #[test]
+    fn should_prefill_filters() {
+        let cmd = MovieDiscover::by_company(420);
+        assert_eq!(cmd.params(), vec![("with_companies", "420".into())]);
+        let cmd = MovieDiscover::by_keyword(9715).with_page(Some(2));
+        assert_eq!(
+            cmd.params(),
+            vec![("page", "2".into()), ("with_keywords", "9715".into())]
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = MovieDiscover::default().execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = MovieDiscover::default().execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::discover::{Filter, MovieSortField, SortBy};
+    use crate::prelude::Command;
+
+    use super::MovieDiscover;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = MovieDiscover::default()
+            .with_genres(Some(Filter::all([28, 12])))
+            .with_sort_by(Some(SortBy::desc(MovieSortField::Popularity)))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+    }
+}
@@ -0,0 +1,36 @@
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+impl ResponseError for super::Error {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.proxy_status_code()).unwrap_or(StatusCode::BAD_GATEWAY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::ResponseError;
+
+    use crate::error::{Error, ServerOtherBodyError};
+
+    fn server_error(code: u16) -> Error {
+        Error::Server {
+            code,
+            content: ServerOtherBodyError {
+                status_code: 34,
+                status_message: "The resource you requested could not be found.".into(),
+            },
+        }
+    }
+
+    #[test]
+    fn should_map_status_codes() {
+        assert_eq!(server_error(404).status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            server_error(429).error_response().status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(server_error(500).status_code(), StatusCode::BAD_GATEWAY);
+    }
+}
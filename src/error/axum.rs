@@ -0,0 +1,44 @@
+use axum_core::response::{IntoResponse, Response};
+use http::StatusCode;
+
+impl IntoResponse for super::Error {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.proxy_status_code()).unwrap_or(StatusCode::BAD_GATEWAY);
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_core::response::IntoResponse;
+    use http::StatusCode;
+
+    use crate::error::{Error, ServerOtherBodyError};
+
+    fn server_error(code: u16) -> Error {
+        Error::Server {
+            code,
+            content: ServerOtherBodyError {
+                status_code: 34,
+                status_message: "The resource you requested could not be found.".into(),
+            },
+        }
+    }
+
+    #[test]
+    fn should_map_status_codes() {
+        assert_eq!(
+            server_error(404).into_response().status(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            server_error(429).into_response().status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            server_error(401).into_response().status(),
+            StatusCode::BAD_GATEWAY
+        );
+    }
+}
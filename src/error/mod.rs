@@ -0,0 +1,252 @@
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ServerOtherBodyError {
+    pub status_code: u16,
+    pub status_message: String,
+}
+
+impl std::error::Error for ServerOtherBodyError {}
+impl std::fmt::Display for ServerOtherBodyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "server body error with code {}: {}",
+            self.status_code, self.status_message
+        )
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ServerValidationBodyError {
+    pub errors: Vec<String>,
+}
+
+impl std::error::Error for ServerValidationBodyError {}
+impl std::fmt::Display for ServerValidationBodyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "server validation body errors:")?;
+        for item in self.errors.iter() {
+            write!(f, ", {item}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, thiserror::Error)]
+#[serde(untagged)]
+pub enum ServerBodyError {
+    #[error(transparent)]
+    Other(#[from] ServerOtherBodyError),
+    #[error(transparent)]
+    Validation(#[from] ServerValidationBodyError),
+}
+
+impl ServerBodyError {
+    pub fn as_other_error(&self) -> Option<&ServerOtherBodyError> {
+        match self {
+            Self::Other(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_validation_error(&self) -> Option<&ServerValidationBodyError> {
+        match self {
+            Self::Validation(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Number of characters of the body shown in the decoding errors.
+const BODY_SNIPPET_LENGTH: usize = 256;
+
+/// Beginning of the body of a response, for the error messages.
+fn body_snippet(body: &[u8]) -> String {
+    crate::util::truncate_body(&String::from_utf8_lossy(body), BODY_SNIPPET_LENGTH).into_owned()
+}
+
+#[derive(Debug)]
+pub struct ServerError {
+    pub code: u16,
+    pub body: ServerBodyError,
+}
+
+impl std::fmt::Display for ServerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "server error with code {}: {}", self.code, self.body)
+    }
+}
+
+impl std::error::Error for ServerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.body)
+    }
+}
+
+#[derive(thiserror::Error)]
+pub enum Error {
+    #[error("couldn't execute request")]
+    Request {
+        #[source]
+        source: Box<dyn std::error::Error + Send>,
+    },
+    #[error("couldn't read response")]
+    Response {
+        #[source]
+        source: Box<dyn std::error::Error + Send>,
+    },
+    /// The response body couldn't be deserialized, the raw body is kept for
+    /// diagnostics, with the path and the status code of the request.
+    #[error(
+        "couldn't decode response body of {path} with status {status_code}: {}",
+        body_snippet(.body)
+    )]
+    Decode {
+        /// Path of the request, without its host and its query, so without
+        /// the api key.
+        path: String,
+        status_code: u16,
+        body: bytes::Bytes,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// The executor can't send requests with this HTTP method.
+    #[error("unsupported http method {0}")]
+    UnsupportedMethod(&'static str),
+    /// The command needs a user session, like the account and list ones, but
+    /// the client is bound to a guest session.
+    #[error("the command requires a user session")]
+    UserSessionRequired,
+    #[error(transparent)]
+    Validation(ServerValidationBodyError),
+    #[error("internal server error with code {code}")]
+    Server {
+        code: u16,
+        #[source]
+        content: ServerOtherBodyError,
+    },
+}
+
+/// Same as the derived implementation, showing only the beginning of the body
+/// of the responses that couldn't be decoded.
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request { source } => f.debug_struct("Request").field("source", source).finish(),
+            Self::Response { source } => {
+                f.debug_struct("Response").field("source", source).finish()
+            }
+            Self::Decode {
+                path,
+                status_code,
+                body,
+                source,
+            } => f
+                .debug_struct("Decode")
+                .field("path", path)
+                .field("status_code", status_code)
+                .field("body", &body_snippet(body))
+                .field("source", source)
+                .finish(),
+            Self::UnsupportedMethod(method) => {
+                f.debug_tuple("UnsupportedMethod").field(method).finish()
+            }
+            Self::UserSessionRequired => f.write_str("UserSessionRequired"),
+            Self::Validation(inner) => f.debug_tuple("Validation").field(inner).finish(),
+            Self::Server { code, content } => f
+                .debug_struct("Server")
+                .field("code", code)
+                .field("content", content)
+                .finish(),
+        }
+    }
+}
+
+impl Error {
+    pub fn as_validation_error(&self) -> Option<&ServerValidationBodyError> {
+        match self {
+            Self::Validation(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_server_error(&self) -> Option<&ServerOtherBodyError> {
+        match self {
+            Self::Server { code: _, content } => Some(content),
+            _ => None,
+        }
+    }
+
+    /// Copy of the errors that can be cloned, to share the response of a
+    /// request between several callers.
+    #[cfg(feature = "commands")]
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        match self {
+            Self::UnsupportedMethod(method) => Some(Self::UnsupportedMethod(method)),
+            Self::UserSessionRequired => Some(Self::UserSessionRequired),
+            Self::Validation(inner) => Some(Self::Validation(inner.clone())),
+            Self::Server { code, content } => Some(Self::Server {
+                code: *code,
+                content: content.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Raw body of a response that couldn't be deserialized.
+    pub fn raw_body(&self) -> Option<&[u8]> {
+        match self {
+            Self::Decode { body, .. } => Some(body),
+            _ => None,
+        }
+    }
+
+    /// Whether the requested resource doesn't exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::Server { code: 404, .. })
+    }
+
+    /// Whether the request was rejected because of the TMDB rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::Server { code: 429, .. })
+    }
+
+    /// HTTP status code a service proxying TMDB should answer with: `404` when
+    /// the resource doesn't exist, `429` when rate limited and `502` otherwise.
+    #[cfg(any(feature = "actix", feature = "axum"))]
+    fn proxy_status_code(&self) -> u16 {
+        if self.is_not_found() {
+            404
+        } else if self.is_rate_limited() {
+            429
+        } else {
+            502
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn should_truncate_decode_body() {
+        let body = bytes::Bytes::from("é".repeat(300));
+        let err = super::Error::Decode {
+            path: "/3/movie/550".into(),
+            status_code: 200,
+            source: serde_json::from_slice::<serde_json::Value>(&body).unwrap_err(),
+            body,
+        };
+        let message = err.to_string();
+        assert!(message
+            .starts_with("couldn't decode response body of /3/movie/550 with status 200: éé"));
+        assert!(message.ends_with("é... (88 more bytes)"));
+        assert_eq!(err.raw_body().unwrap().len(), 600);
+        let debug = format!("{err:?}");
+        assert!(debug.starts_with(r#"Decode { path: "/3/movie/550", status_code: 200, body: "éé"#));
+        assert!(debug.contains(r#"é... (88 more bytes)", source: Error("#));
+    }
+}
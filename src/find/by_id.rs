@@ -0,0 +1,333 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use super::{ExternalId, ExternalIdError, ExternalIdSource};
+
+const PATH: &str = "/find/{external_id}";
+
+/// Command to find movies, people or tvshows using an external ID
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::find::by_id::FindById;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = FindById::imdb_id("tt0137523");
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FindById {
+    /// ID in the external source.
+    pub external_id: String,
+    /// Source the external ID comes from.
+    pub external_source: ExternalIdSource,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+}
+
+/// Writes the ID as a single segment of the path, percent-encoding anything
+/// but the unreserved characters, given that the handles and the IDs pasted by
+/// users can contain spaces, slashes or question marks.
+fn write_segment(out: &mut String, value: &str) {
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => {
+                let _ = write!(out, "%{byte:02X}");
+            }
+        }
+    }
+}
+
+fn strip_handle(value: &str) -> String {
+    value.trim().trim_start_matches('@').to_string()
+}
+
+impl FindById {
+    pub fn new(external_id: String, external_source: ExternalIdSource) -> Self {
+        Self {
+            external_id,
+            external_source,
+            language: None,
+        }
+    }
+
+    /// Find using an ID extracted from a URL of the external source,
+    /// like `https://www.imdb.com/title/tt0137523/`.
+    pub fn from_url(url: &str) -> Result<Self, ExternalIdError> {
+        ExternalId::from_url(url).map(Self::from)
+    }
+
+    /// Find using an IMDb ID, like `tt0137523` for a movie or `nm0000093` for a person.
+    pub fn imdb_id(value: &str) -> Self {
+        Self::new(value.trim().to_string(), ExternalIdSource::ImdbId)
+    }
+
+    /// Find using a TheTVDB ID.
+    pub fn tvdb_id(value: u64) -> Self {
+        Self::new(value.to_string(), ExternalIdSource::TvdbId)
+    }
+
+    /// Find using a Wikidata ID. The `Q` prefix is added when missing.
+    pub fn wikidata_id(value: &str) -> Self {
+        let value = value.trim();
+        let value = if value.starts_with(['Q', 'q']) {
+            format!("Q{}", &value[1..])
+        } else {
+            format!("Q{value}")
+        };
+        Self::new(value, ExternalIdSource::WikidataId)
+    }
+
+    /// Find using a Facebook ID.
+    pub fn facebook_id(value: &str) -> Self {
+        Self::new(value.trim().to_string(), ExternalIdSource::FacebookId)
+    }
+
+    /// Find using an Instagram handle, with or without the leading `@`.
+    pub fn instagram_id(value: &str) -> Self {
+        Self::new(strip_handle(value), ExternalIdSource::InstagramId)
+    }
+
+    /// Find using a TikTok handle, with or without the leading `@`.
+    pub fn tiktok_id(value: &str) -> Self {
+        Self::new(strip_handle(value), ExternalIdSource::TiktokId)
+    }
+
+    /// Find using a Twitter handle, with or without the leading `@`.
+    pub fn twitter_id(value: &str) -> Self {
+        Self::new(strip_handle(value), ExternalIdSource::TwitterId)
+    }
+
+    /// Find using a YouTube ID.
+    pub fn youtube_id(value: &str) -> Self {
+        Self::new(value.trim().to_string(), ExternalIdSource::YoutubeId)
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+}
+
+impl From<ExternalId> for FindById {
+    fn from(value: ExternalId) -> Self {
+        Self::new(value.id, value.source)
+    }
+}
+
+impl crate::prelude::Command for FindById {
+    type Output = super::FindResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        out.push_str("/find/");
+        write_segment(out, &self.external_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = vec![(
+            "external_source",
+            Cow::Borrowed(self.external_source.as_str()),
+        )];
+        if let Some(language) = self.language.as_ref() {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        res
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::find::ExternalIdSource;
+    use crate::prelude::Command;
+
+    use super::FindById;
+
+    #[test]
+    fn should_format_ids() {
+        let cmd = FindById::tvdb_id(81189);
+        assert_eq!(cmd.external_id, "81189");
+        assert_eq!(cmd.external_source, ExternalIdSource::TvdbId);
+
+        let cmd = FindById::from_url("https://www.imdb.com/title/tt0137523/").unwrap();
+        assert_eq!(cmd.external_id, "tt0137523");
+        assert_eq!(cmd.external_source, ExternalIdSource::ImdbId);
+
+        assert_eq!(FindById::wikidata_id("Q190050").external_id, "Q190050");
+        assert_eq!(FindById::wikidata_id("q190050").external_id, "Q190050");
+        assert_eq!(FindById::wikidata_id("190050").external_id, "Q190050");
+
+        let cmd = FindById::twitter_id("@BreakingBad");
+        assert_eq!(cmd.external_id, "BreakingBad");
+        assert_eq!(cmd.external_source, ExternalIdSource::TwitterId);
+        assert_eq!(
+            FindById::instagram_id("breakingbad").external_id,
+            "breakingbad"
+        );
+    }
+
+    #[test]
+    fn should_encode_external_id() {
+        assert_eq!(FindById::imdb_id("tt0137523").path(), "/find/tt0137523");
+        assert_eq!(
+            FindById::facebook_id("Breaking Bad/?#%é").path(),
+            "/find/Breaking%20Bad%2F%3F%23%25%C3%A9"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/find/tt0137523")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("external_source".into(), "imdb_id".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/find-imdb.json"))
+            .create_async()
+            .await;
+
+        let result = FindById::imdb_id("tt0137523")
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.movie_results.len(), 1);
+        assert_eq!(result.movie_results[0].inner.id, 550);
+    }
+
+    #[tokio::test]
+    async fn tvdb_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/find/3254641")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("external_source".into(), "tvdb_id".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/find-tvdb.json"))
+            .create_async()
+            .await;
+
+        let result = FindById::tvdb_id(3254641).execute(&client).await.unwrap();
+        assert_eq!(result.tv_episode_results.len(), 1);
+        assert_eq!(result.tv_episode_results[0].id, 63056);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/find/tt0137523")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = FindById::imdb_id("tt0137523")
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/find/tt0137523")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = FindById::imdb_id("tt0137523")
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::FindById;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = FindById::imdb_id("tt0137523")
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.movie_results[0].inner.id, 550);
+
+        let result = FindById::tvdb_id(81189).execute(&client).await.unwrap();
+        assert_eq!(result.tv_results[0].inner.id, 1396);
+    }
+}
@@ -0,0 +1,323 @@
+#[cfg(feature = "commands")]
+pub mod by_id;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::movie::MovieShort;
+use crate::people::PersonShort;
+use crate::tvshow::{EpisodeShort, SeasonShort, TVShowShort};
+
+/// External source of an ID to look up with [by_id::FindById].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalIdSource {
+    ImdbId,
+    FacebookId,
+    InstagramId,
+    TvdbId,
+    TiktokId,
+    TwitterId,
+    WikidataId,
+    YoutubeId,
+}
+
+impl ExternalIdSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ImdbId => "imdb_id",
+            Self::FacebookId => "facebook_id",
+            Self::InstagramId => "instagram_id",
+            Self::TvdbId => "tvdb_id",
+            Self::TiktokId => "tiktok_id",
+            Self::TwitterId => "twitter_id",
+            Self::WikidataId => "wikidata_id",
+            Self::YoutubeId => "youtube_id",
+        }
+    }
+}
+
+impl Display for ExternalIdSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExternalIdSource {
+    type Err = ExternalIdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim_end_matches("_id") {
+            "imdb" => Ok(Self::ImdbId),
+            "facebook" => Ok(Self::FacebookId),
+            "instagram" => Ok(Self::InstagramId),
+            "tvdb" => Ok(Self::TvdbId),
+            "tiktok" => Ok(Self::TiktokId),
+            "twitter" => Ok(Self::TwitterId),
+            "wikidata" => Ok(Self::WikidataId),
+            "youtube" => Ok(Self::YoutubeId),
+            _ => Err(ExternalIdError::UnknownSource(value.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ExternalIdError {
+    #[error("unknown external source {0:?}")]
+    UnknownSource(String),
+    #[error("unable to find an external id in {0:?}")]
+    InvalidUrl(String),
+}
+
+/// ID coming from an external source, like IMDb or Wikidata.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ExternalId {
+    pub source: ExternalIdSource,
+    pub id: String,
+}
+
+impl ExternalId {
+    pub fn new(source: ExternalIdSource, id: impl Into<String>) -> Self {
+        Self {
+            source,
+            id: id.into(),
+        }
+    }
+
+    /// Extracts the external ID from a URL of the external source.
+    ///
+    /// ```rust
+    /// use tmdb_api::find::{ExternalId, ExternalIdSource};
+    ///
+    /// let found = ExternalId::from_url("https://www.imdb.com/title/tt0137523/").unwrap();
+    /// assert_eq!(found, ExternalId::new(ExternalIdSource::ImdbId, "tt0137523"));
+    /// ```
+    pub fn from_url(url: &str) -> Result<Self, ExternalIdError> {
+        let invalid = || ExternalIdError::InvalidUrl(url.to_string());
+
+        let trimmed = url.trim();
+        let trimmed = trimmed.split_once("://").map_or(trimmed, |(_, rest)| rest);
+        let (location, query) = match trimmed.split_once(['?', '#']) {
+            Some((location, query)) => (location, Some(query)),
+            None => (trimmed, None),
+        };
+        let (host, path) = location.split_once('/').unwrap_or((location, ""));
+        let host = host.to_ascii_lowercase();
+        let host = host
+            .trim_start_matches("www.")
+            .trim_start_matches("m.")
+            .trim_start_matches("mobile.");
+        let segments: Vec<&str> = path.split('/').filter(|item| !item.is_empty()).collect();
+
+        let (source, id) = match host {
+            "imdb.com" => segments
+                .iter()
+                .find(|item| item.starts_with("tt") || item.starts_with("nm"))
+                .map(|item| (ExternalIdSource::ImdbId, item.to_string())),
+            "thetvdb.com" => query
+                .and_then(|query| {
+                    query
+                        .split('&')
+                        .find_map(|pair| pair.strip_prefix("id="))
+                        .filter(|id| is_numeric(id))
+                })
+                .or_else(|| segments.iter().rev().copied().find(|item| is_numeric(item)))
+                .map(|id| (ExternalIdSource::TvdbId, id.to_string())),
+            "wikidata.org" => segments
+                .iter()
+                .find(|item| item.starts_with('Q') && is_numeric(&item[1..]))
+                .map(|item| (ExternalIdSource::WikidataId, item.to_string())),
+            "facebook.com" => segments
+                .first()
+                .map(|item| (ExternalIdSource::FacebookId, item.to_string())),
+            "instagram.com" => segments
+                .first()
+                .map(|item| (ExternalIdSource::InstagramId, item.to_string())),
+            "tiktok.com" => segments.first().map(|item| {
+                let item = item.trim_start_matches('@');
+                (ExternalIdSource::TiktokId, item.to_string())
+            }),
+            "twitter.com" | "x.com" => segments
+                .first()
+                .map(|item| (ExternalIdSource::TwitterId, item.to_string())),
+            "youtube.com" => match segments.as_slice() {
+                ["channel" | "c" | "user", item, ..] => Some(item.to_string()),
+                [item, ..] if item.starts_with('@') => Some(item[1..].to_string()),
+                _ => None,
+            }
+            .map(|item| (ExternalIdSource::YoutubeId, item)),
+            _ => None,
+        }
+        .filter(|(_, id)| !id.is_empty())
+        .ok_or_else(invalid)?;
+
+        Ok(Self { source, id })
+    }
+}
+
+fn is_numeric(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|c| c.is_ascii_digit())
+}
+
+impl Display for ExternalId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.source, self.id)
+    }
+}
+
+impl FromStr for ExternalId {
+    type Err = ExternalIdError;
+
+    /// Parses either a `source:id` value, like `imdb_id:tt0137523`, or a URL.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.contains('/') {
+            return Self::from_url(value);
+        }
+        match value.split_once(':') {
+            Some((source, id)) if !id.is_empty() => Ok(Self::new(source.parse()?, id)),
+            _ => Err(ExternalIdError::InvalidUrl(value.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FindResult {
+    #[serde(default)]
+    pub movie_results: Vec<MovieShort>,
+    #[serde(default)]
+    pub person_results: Vec<PersonShort>,
+    #[serde(default)]
+    pub tv_results: Vec<TVShowShort>,
+    #[serde(default)]
+    pub tv_episode_results: Vec<EpisodeShort>,
+    #[serde(default)]
+    pub tv_season_results: Vec<SeasonShort>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExternalId, ExternalIdError, ExternalIdSource, FindResult};
+
+    #[test]
+    fn should_accept_missing_lists() {
+        let result: FindResult = serde_json::from_str(
+            r#"{"tv_results":[{"id":1396,"name":"Breaking Bad","original_name":"Breaking Bad","original_language":"en","first_air_date":"2008-01-20","popularity":381.47,"vote_count":12000,"vote_average":8.9}]}"#,
+        )
+        .unwrap();
+        assert!(result.movie_results.is_empty());
+        assert_eq!(result.tv_results[0].inner.id, 1396);
+        assert!(result.tv_results[0].inner.origin_country.is_empty());
+        assert!(result.tv_results[0].genre_ids.is_empty());
+    }
+
+    #[test]
+    fn should_parse_source() {
+        assert_eq!(
+            "imdb_id".parse::<ExternalIdSource>().unwrap(),
+            ExternalIdSource::ImdbId
+        );
+        assert_eq!(
+            "wikidata".parse::<ExternalIdSource>().unwrap(),
+            ExternalIdSource::WikidataId
+        );
+        assert!("netflix_id".parse::<ExternalIdSource>().is_err());
+        for source in [ExternalIdSource::TvdbId, ExternalIdSource::YoutubeId] {
+            assert_eq!(
+                source.to_string().parse::<ExternalIdSource>().unwrap(),
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn should_parse_urls() {
+        let cases = [
+            (
+                "https://www.imdb.com/title/tt0137523/?ref_=fn_al_tt_1",
+                ExternalIdSource::ImdbId,
+                "tt0137523",
+            ),
+            (
+                "https://m.imdb.com/name/nm0000093",
+                ExternalIdSource::ImdbId,
+                "nm0000093",
+            ),
+            (
+                "https://thetvdb.com/?tab=series&id=81189",
+                ExternalIdSource::TvdbId,
+                "81189",
+            ),
+            (
+                "https://thetvdb.com/dereferrer/series/81189",
+                ExternalIdSource::TvdbId,
+                "81189",
+            ),
+            (
+                "https://www.wikidata.org/wiki/Q190050",
+                ExternalIdSource::WikidataId,
+                "Q190050",
+            ),
+            (
+                "https://twitter.com/BreakingBad",
+                ExternalIdSource::TwitterId,
+                "BreakingBad",
+            ),
+            (
+                "https://x.com/BreakingBad",
+                ExternalIdSource::TwitterId,
+                "BreakingBad",
+            ),
+            (
+                "https://www.tiktok.com/@breakingbad",
+                ExternalIdSource::TiktokId,
+                "breakingbad",
+            ),
+            (
+                "https://www.youtube.com/@BreakingBad",
+                ExternalIdSource::YoutubeId,
+                "BreakingBad",
+            ),
+            (
+                "imdb.com/title/tt0137523",
+                ExternalIdSource::ImdbId,
+                "tt0137523",
+            ),
+        ];
+        for (url, source, id) in cases {
+            assert_eq!(
+                ExternalId::from_url(url).unwrap(),
+                ExternalId::new(source, id),
+                "with {url}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_urls() {
+        for url in [
+            "https://thetvdb.com/series/breaking-bad",
+            "https://www.themoviedb.org/movie/550",
+            "https://www.imdb.com/",
+        ] {
+            assert_eq!(
+                ExternalId::from_url(url).unwrap_err(),
+                ExternalIdError::InvalidUrl(url.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn should_parse_from_str() {
+        let value: ExternalId = "imdb_id:tt0137523".parse().unwrap();
+        assert_eq!(
+            value,
+            ExternalId::new(ExternalIdSource::ImdbId, "tt0137523")
+        );
+        assert_eq!(value.to_string(), "imdb_id:tt0137523");
+        let value: ExternalId = "https://www.wikidata.org/wiki/Q190050".parse().unwrap();
+        assert_eq!(value.source, ExternalIdSource::WikidataId);
+        assert!("tt0137523".parse::<ExternalId>().is_err());
+    }
+}
@@ -70,6 +70,10 @@ impl crate::prelude::Command for GenreList {
         Cow::Borrowed(self.path)
     }
 
+    fn path_template(&self) -> &'static str {
+        self.path
+    }
+
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
         if let Some(language) = self.language.as_ref() {
             vec![("language", Cow::Borrowed(language.as_str()))]
@@ -89,7 +93,7 @@ impl crate::prelude::Command for GenreList {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
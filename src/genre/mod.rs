@@ -2,7 +2,26 @@
 pub mod list;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Genre {
     pub id: u64,
     pub name: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Genre;
+
+    #[test]
+    fn should_handle_unknown_fields() {
+        let result = serde_json::from_str::<Genre>(r#"{"id":28,"name":"Action","slug":"action"}"#);
+        if cfg!(feature = "strict") {
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("unknown field `slug`"));
+        } else {
+            assert_eq!(result.unwrap().name, "Action");
+        }
+    }
+}
@@ -0,0 +1,2 @@
+#[cfg(feature = "commands")]
+pub mod movies;
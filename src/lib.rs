@@ -11,24 +11,39 @@ extern crate serde;
 extern crate serde_repr;
 
 /// The used version of reqwest
-#[cfg(feature = "commands")]
+#[cfg(feature = "reqwest")]
 pub use reqwest;
 
 #[cfg(feature = "commands")]
 pub use client::Client;
 
+pub mod account;
+pub mod authentication;
+#[cfg(feature = "commands")]
+pub mod batch;
 pub mod certification;
 pub mod changes;
 #[cfg(feature = "commands")]
 pub mod client;
 pub mod collection;
 pub mod company;
+pub mod discover;
 pub mod error;
+pub mod find;
 pub mod genre;
+pub mod keyword;
+pub mod list;
 pub mod movie;
+#[cfg(feature = "commands")]
+pub mod pagination;
 pub mod people;
 #[cfg(feature = "commands")]
 pub mod prelude;
+pub mod review;
+pub mod search;
+#[cfg(all(test, feature = "commands"))]
+mod snapshot;
+pub mod trending;
 pub mod tvshow;
 pub mod watch_provider;
 
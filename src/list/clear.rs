@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::authentication::client::UserSessionCommand;
+use crate::client::Method;
+use crate::common::StatusResult;
+use crate::prelude::Command;
+
+const PATH: &str = "/list/{list_id}/clear";
+
+/// Command to remove all the items of a list owned by the user of the session.
+///
+/// The list is only cleared when `confirm` is `true`, TMDB rejects the
+/// command otherwise.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::list::clear::ListClear;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ListClear::new(1, "this-is-my-session-id".into(), true);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListClear {
+    /// ID of the list.
+    pub list_id: u64,
+    /// ID of the user session.
+    pub session_id: String,
+    /// Confirms that the list should be cleared.
+    pub confirm: bool,
+}
+
+impl ListClear {
+    pub fn new(list_id: u64, session_id: String, confirm: bool) -> Self {
+        Self {
+            list_id,
+            session_id,
+            confirm,
+        }
+    }
+}
+
+impl Command for ListClear {
+    type Output = StatusResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/list/{}/clear", self.list_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        vec![
+            ("session_id", Cow::Borrowed(self.session_id.as_str())),
+            (
+                "confirm",
+                Cow::Borrowed(if self.confirm { "true" } else { "false" }),
+            ),
+        ]
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+}
+
+impl UserSessionCommand for ListClear {
+    fn set_session_id(&mut self, value: String) {
+        self.session_id = value;
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::ListClear;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", "/list/1/clear")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+                Matcher::UrlEncoded("confirm".into(), "true".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/list-clear.json"))
+            .create_async()
+            .await;
+
+        let result = ListClear::new(1, "session".into(), true)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, 12);
+        m.assert_async().await;
+    }
+
+    #[test]
+    fn should_not_confirm_by_mistake() {
+        let cmd = ListClear::new(1, "session".into(), false);
+        assert_eq!(
+            cmd.params(),
+            vec![
+                ("session_id", "session".into()),
+                ("confirm", "false".into())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/list/1/clear")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = ListClear::new(1, "session".into(), true)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/list/1/clear")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListClear::new(1, "session".into(), true)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,67 @@
+//! Lists created by the users, with the v3 API.
+
+#[cfg(feature = "commands")]
+pub mod clear;
+#[cfg(feature = "commands")]
+pub mod details;
+#[cfg(feature = "commands")]
+pub mod item_status;
+#[cfg(feature = "commands")]
+pub mod remove_item;
+pub mod v4;
+
+use crate::movie::MovieShort;
+use crate::tvshow::TVShowShort;
+
+/// Movie or TV show of a list, tagged by its `media_type`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum ListItem {
+    Movie(MovieShort),
+    Tv(TVShowShort),
+}
+
+impl ListItem {
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::Movie(inner) => inner.inner.id,
+            Self::Tv(inner) => inner.inner.id,
+        }
+    }
+
+    /// Title of a movie or name of a TV show.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Movie(inner) => &inner.inner.title,
+            Self::Tv(inner) => &inner.inner.name,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ListDetailsResult {
+    pub id: u64,
+    pub name: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub created_by: String,
+    pub favorite_count: u64,
+    pub item_count: u64,
+    pub iso_639_1: String,
+    pub poster_path: Option<String>,
+    pub items: Vec<ListItem>,
+    #[serde(default)]
+    pub page: u64,
+    #[serde(default)]
+    pub total_pages: u64,
+    #[serde(default)]
+    pub total_results: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ListItemStatusResult {
+    pub id: u64,
+    pub item_present: bool,
+}
@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+use crate::prelude::Command;
+
+const PATH: &str = "/4/list";
+
+/// Command to create a list, owned by the user of the access token.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::list::v4::create::ListCreate;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ListCreate::new("this-is-my-access-token".into(), "My list".into(), "en".into())
+///         .with_public(Some(false));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ListCreate {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    pub name: String,
+    /// ISO 639-1 code of the language of the list.
+    pub iso_639_1: String,
+    pub description: Option<String>,
+    /// Whether the list is visible by the other users.
+    pub public: Option<bool>,
+}
+
+impl ListCreate {
+    pub fn new(access_token: String, name: String, iso_639_1: String) -> Self {
+        Self {
+            access_token,
+            name,
+            iso_639_1,
+            description: None,
+            public: None,
+        }
+    }
+
+    pub fn with_description(mut self, value: Option<String>) -> Self {
+        self.description = value;
+        self
+    }
+
+    pub fn with_public(mut self, value: Option<bool>) -> Self {
+        self.public = value;
+        self
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for ListCreate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListCreate")
+            .field("access_token", &"redacted")
+            .field("name", &self.name)
+            .field("iso_639_1", &self.iso_639_1)
+            .field("description", &self.description)
+            .field("public", &self.public)
+            .finish()
+    }
+}
+
+impl Command for ListCreate {
+    type Output = super::ListCreateResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        let mut body = serde_json::json!({
+            "name": self.name,
+            "iso_639_1": self.iso_639_1,
+        });
+        if let Some(ref description) = self.description {
+            body["description"] = description.as_str().into();
+        }
+        if let Some(public) = self.public {
+            body["public"] = public.into();
+        }
+        Some(body)
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::ListCreate;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("authorization", "Bearer token")
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(serde_json::json!({
+                "name": "My list",
+                "iso_639_1": "en",
+                "public": false,
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/list-v4-create.json"))
+            .create_async()
+            .await;
+
+        let result = ListCreate::new("token".into(), "My list".into(), "en".into())
+            .with_public(Some(false))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.inner.success);
+        assert_eq!(result.id, 14184);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", super::PATH)
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = ListCreate::new("token".into(), "My list".into(), "en".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
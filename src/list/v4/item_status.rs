@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::MediaType;
+use crate::prelude::Command;
+
+const PATH: &str = "/4/list/{list_id}/item_status";
+
+/// Command to check whether a movie or a TV show is in a list, with the
+/// access token of the user.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::MediaType;
+/// use tmdb_api::list::v4::item_status::ListItemStatus;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ListItemStatus::new("this-is-my-access-token".into(), 14184, MediaType::Movie, 550);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ListItemStatus {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// ID of the list.
+    pub list_id: u64,
+    pub media_type: MediaType,
+    pub media_id: u64,
+}
+
+impl ListItemStatus {
+    pub fn new(access_token: String, list_id: u64, media_type: MediaType, media_id: u64) -> Self {
+        Self {
+            access_token,
+            list_id,
+            media_type,
+            media_id,
+        }
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for ListItemStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListItemStatus")
+            .field("access_token", &"redacted")
+            .field("list_id", &self.list_id)
+            .field("media_type", &self.media_type)
+            .field("media_id", &self.media_id)
+            .finish()
+    }
+}
+
+impl Command for ListItemStatus {
+    type Output = super::ListItemStatusResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/4/list/{}/item_status", self.list_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        vec![
+            ("media_id", Cow::Owned(self.media_id.to_string())),
+            ("media_type", Cow::Owned(self.media_type.to_string())),
+        ]
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::MediaType;
+    use crate::prelude::Command;
+
+    use super::ListItemStatus;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/list/14184/item_status")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("media_id".into(), "550".into()),
+                Matcher::UrlEncoded("media_type".into(), "movie".into()),
+            ]))
+            .match_header("authorization", "Bearer token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/list-v4-item-status.json"))
+            .create_async()
+            .await;
+
+        let result = ListItemStatus::new("token".into(), 14184, MediaType::Movie, 550)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.inner.success);
+        assert_eq!(result.media_id, 550);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/4/list/14184/item_status")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListItemStatus::new("token".into(), 14184, MediaType::Movie, 550)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,421 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::client::Method;
+use crate::prelude::Command;
+
+use super::{ListItemEntry, ListItemsResult};
+
+const PATH: &str = "/4/list/{list_id}/items";
+
+fn write_items_path(out: &mut String, list_id: u64) {
+    let _ = write!(out, "/4/list/{}/items", list_id);
+}
+
+/// Command to add movies or TV shows, with an optional comment, in a list owned by the user of the access token.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::MediaType;
+/// use tmdb_api::list::v4::ListItemEntry;
+/// use tmdb_api::list::v4::items::ListAddItems;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let items = vec![ListItemEntry::new(MediaType::Movie, 550).with_comment(Some("Classic".into()))];
+///     let cmd = ListAddItems::new("this-is-my-access-token".into(), 14184, items);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ListAddItems {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// ID of the list.
+    pub list_id: u64,
+    pub items: Vec<ListItemEntry>,
+}
+
+impl ListAddItems {
+    pub fn new(access_token: String, list_id: u64, items: Vec<ListItemEntry>) -> Self {
+        Self {
+            access_token,
+            list_id,
+            items,
+        }
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for ListAddItems {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListAddItems")
+            .field("access_token", &"redacted")
+            .field("list_id", &self.list_id)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl Command for ListAddItems {
+    type Output = ListItemsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        write_items_path(out, self.list_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "items": self.items }))
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+/// Command to update the comments of movies or TV shows, in a list owned by the user of the access token.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::MediaType;
+/// use tmdb_api::list::v4::ListItemEntry;
+/// use tmdb_api::list::v4::items::ListUpdateItems;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let items = vec![ListItemEntry::new(MediaType::Movie, 550).with_comment(Some("Classic".into()))];
+///     let cmd = ListUpdateItems::new("this-is-my-access-token".into(), 14184, items);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ListUpdateItems {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// ID of the list.
+    pub list_id: u64,
+    pub items: Vec<ListItemEntry>,
+}
+
+impl ListUpdateItems {
+    pub fn new(access_token: String, list_id: u64, items: Vec<ListItemEntry>) -> Self {
+        Self {
+            access_token,
+            list_id,
+            items,
+        }
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for ListUpdateItems {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListUpdateItems")
+            .field("access_token", &"redacted")
+            .field("list_id", &self.list_id)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl Command for ListUpdateItems {
+    type Output = ListItemsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        write_items_path(out, self.list_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Put
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "items": self.items }))
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+/// Command to remove movies or TV shows, in a list owned by the user of the access token.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::MediaType;
+/// use tmdb_api::list::v4::ListItemEntry;
+/// use tmdb_api::list::v4::items::ListRemoveItems;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let items = vec![ListItemEntry::new(MediaType::Movie, 550)];
+///     let cmd = ListRemoveItems::new("this-is-my-access-token".into(), 14184, items);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ListRemoveItems {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// ID of the list.
+    pub list_id: u64,
+    pub items: Vec<ListItemEntry>,
+}
+
+impl ListRemoveItems {
+    pub fn new(access_token: String, list_id: u64, items: Vec<ListItemEntry>) -> Self {
+        Self {
+            access_token,
+            list_id,
+            items,
+        }
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for ListRemoveItems {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListRemoveItems")
+            .field("access_token", &"redacted")
+            .field("list_id", &self.list_id)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl Command for ListRemoveItems {
+    type Output = ListItemsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        write_items_path(out, self.list_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        // the comments are meaningless when removing the items
+        let items: Vec<_> = self
+            .items
+            .iter()
+            .map(|item| ListItemEntry::new(item.media_type, item.media_id))
+            .collect();
+        Some(serde_json::json!({ "items": items }))
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::MediaType;
+    use crate::list::v4::ListItemEntry;
+    use crate::prelude::Command;
+
+    use super::{ListAddItems, ListRemoveItems, ListUpdateItems};
+
+    fn items() -> Vec<ListItemEntry> {
+        vec![
+            ListItemEntry::new(MediaType::Movie, 550).with_comment(Some("Classic".into())),
+            ListItemEntry::new(MediaType::Tv, 1399),
+        ]
+    }
+
+    #[tokio::test]
+    async fn add_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", "/4/list/14184/items")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("authorization", "Bearer token")
+            .match_body(Matcher::Json(serde_json::json!({
+                "items": [
+                    { "media_type": "movie", "media_id": 550, "comment": "Classic" },
+                    { "media_type": "tv", "media_id": 1399 },
+                ]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/list-v4-items.json"))
+            .create_async()
+            .await;
+
+        let result = ListAddItems::new("token".into(), 14184, items())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.inner.success);
+        assert_eq!(result.results.len(), 2);
+        assert_eq!(result.results[1].media_type, MediaType::Tv);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn update_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("PUT", "/4/list/14184/items")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("authorization", "Bearer token")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "items": [{ "media_type": "movie", "media_id": 550, "comment": "Classic" }]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/list-v4-items.json"))
+            .create_async()
+            .await;
+
+        let result = ListUpdateItems::new("token".into(), 14184, items()[..1].to_vec())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.inner.success);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn remove_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("DELETE", "/4/list/14184/items")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("authorization", "Bearer token")
+            .match_body(Matcher::Json(serde_json::json!({
+                "items": [
+                    { "media_type": "movie", "media_id": 550 },
+                    { "media_type": "tv", "media_id": 1399 },
+                ]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/list-v4-items.json"))
+            .create_async()
+            .await;
+
+        let result = ListRemoveItems::new("token".into(), 14184, items())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.inner.success);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/4/list/14184/items")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListAddItems::new("token".into(), 14184, items())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
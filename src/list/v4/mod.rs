@@ -0,0 +1,128 @@
+//! Lists created by the users, with the v4 API supporting both movies and
+//! TV shows. The commands are authenticated with the access token of the user.
+
+#[cfg(feature = "commands")]
+pub mod create;
+#[cfg(feature = "commands")]
+pub mod item_status;
+#[cfg(feature = "commands")]
+pub mod items;
+#[cfg(feature = "commands")]
+pub mod update;
+
+use crate::common::{MediaType, StatusResult};
+
+/// Order of the items of a list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ListSortBy {
+    #[serde(rename = "original_order.asc")]
+    OriginalOrderAsc,
+    #[serde(rename = "original_order.desc")]
+    OriginalOrderDesc,
+    #[serde(rename = "vote_average.asc")]
+    VoteAverageAsc,
+    #[serde(rename = "vote_average.desc")]
+    VoteAverageDesc,
+    #[serde(rename = "primary_release_date.asc")]
+    PrimaryReleaseDateAsc,
+    #[serde(rename = "primary_release_date.desc")]
+    PrimaryReleaseDateDesc,
+    #[serde(rename = "title.asc")]
+    TitleAsc,
+    #[serde(rename = "title.desc")]
+    TitleDesc,
+}
+
+impl ListSortBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OriginalOrderAsc => "original_order.asc",
+            Self::OriginalOrderDesc => "original_order.desc",
+            Self::VoteAverageAsc => "vote_average.asc",
+            Self::VoteAverageDesc => "vote_average.desc",
+            Self::PrimaryReleaseDateAsc => "primary_release_date.asc",
+            Self::PrimaryReleaseDateDesc => "primary_release_date.desc",
+            Self::TitleAsc => "title.asc",
+            Self::TitleDesc => "title.desc",
+        }
+    }
+}
+
+impl std::fmt::Display for ListSortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListCreateResult {
+    #[serde(flatten)]
+    pub inner: StatusResult,
+    /// ID of the created list.
+    pub id: u64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+/// Movie or TV show to add, update or remove from a list.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ListItemEntry {
+    pub media_type: MediaType,
+    pub media_id: u64,
+    /// Comment of the user about the item, ignored when removing it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl ListItemEntry {
+    pub fn new(media_type: MediaType, media_id: u64) -> Self {
+        Self {
+            media_type,
+            media_id,
+            comment: None,
+        }
+    }
+
+    pub fn with_comment(mut self, value: Option<String>) -> Self {
+        self.comment = value;
+        self
+    }
+}
+
+/// Outcome of the change of a single item of a list.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ListItemResult {
+    pub media_type: MediaType,
+    pub media_id: u64,
+    pub success: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListItemsResult {
+    #[serde(flatten)]
+    pub inner: StatusResult,
+    #[serde(default)]
+    pub results: Vec<ListItemResult>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListItemStatusResult {
+    #[serde(flatten)]
+    pub inner: StatusResult,
+    /// ID of the item in the list.
+    pub id: u64,
+    pub media_type: MediaType,
+    pub media_id: u64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
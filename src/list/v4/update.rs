@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::client::Method;
+use crate::common::StatusResult;
+use crate::prelude::Command;
+
+use super::ListSortBy;
+
+const PATH: &str = "/4/list/{list_id}";
+
+/// Command to update the details of a list owned by the user of the access
+/// token, only the given fields are updated.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::list::v4::ListSortBy;
+/// use tmdb_api::list::v4::update::ListUpdate;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ListUpdate::new("this-is-my-access-token".into(), 14184)
+///         .with_sort_by(Some(ListSortBy::TitleAsc));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ListUpdate {
+    /// Access token of the user, from the v4 authentication.
+    pub access_token: String,
+    /// ID of the list.
+    pub list_id: u64,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// Whether the list is visible by the other users.
+    pub public: Option<bool>,
+    pub sort_by: Option<ListSortBy>,
+}
+
+impl ListUpdate {
+    pub fn new(access_token: String, list_id: u64) -> Self {
+        Self {
+            access_token,
+            list_id,
+            name: None,
+            description: None,
+            public: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_name(mut self, value: Option<String>) -> Self {
+        self.name = value;
+        self
+    }
+
+    pub fn with_description(mut self, value: Option<String>) -> Self {
+        self.description = value;
+        self
+    }
+
+    pub fn with_public(mut self, value: Option<bool>) -> Self {
+        self.public = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<ListSortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+/// Keeps the access token out of the logs.
+impl std::fmt::Debug for ListUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListUpdate")
+            .field("access_token", &"redacted")
+            .field("list_id", &self.list_id)
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("public", &self.public)
+            .field("sort_by", &self.sort_by)
+            .finish()
+    }
+}
+
+impl Command for ListUpdate {
+    type Output = StatusResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/4/list/{}", self.list_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::Put
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        let mut body = serde_json::json!({});
+        if let Some(ref name) = self.name {
+            body["name"] = name.as_str().into();
+        }
+        if let Some(ref description) = self.description {
+            body["description"] = description.as_str().into();
+        }
+        if let Some(public) = self.public {
+            body["public"] = public.into();
+        }
+        if let Some(sort_by) = self.sort_by {
+            body["sort_by"] = sort_by.as_str().into();
+        }
+        Some(body)
+    }
+
+    fn access_token(&self) -> Option<&str> {
+        Some(self.access_token.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::list::v4::ListSortBy;
+    use crate::prelude::Command;
+
+    use super::ListUpdate;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("PUT", "/4/list/14184")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_header("authorization", "Bearer token")
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(serde_json::json!({
+                "description": "Movies and shows to watch",
+                "sort_by": "title.asc",
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/list-v4-update.json"))
+            .create_async()
+            .await;
+
+        let result = ListUpdate::new("token".into(), 14184)
+            .with_description(Some("Movies and shows to watch".into()))
+            .with_sort_by(Some(ListSortBy::TitleAsc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, 12);
+        m.assert_async().await;
+    }
+
+    #[test]
+    fn should_not_debug_access_token() {
+        let cmd = ListUpdate::new("token".into(), 14184);
+        assert!(!format!("{cmd:?}").contains("\"token\""));
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("PUT", "/4/list/14184")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListUpdate::new("token".into(), 14184)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
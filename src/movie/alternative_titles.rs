@@ -1,4 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+const PATH: &str = "/movie/{movie_id}/alternative_titles";
 
 /// Command to get alternative titles for a movie
 ///
@@ -41,7 +44,8 @@ impl MovieAlternativeTitles {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieAlternativeTitle {
     pub iso_3166_1: String,
     pub title: String,
@@ -52,7 +56,8 @@ pub struct MovieAlternativeTitle {
     pub kind: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieAlternativeTitlesResult {
     pub id: u64,
     pub titles: Vec<MovieAlternativeTitle>,
@@ -62,7 +67,17 @@ impl crate::prelude::Command for MovieAlternativeTitles {
     type Output = MovieAlternativeTitlesResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/alternative_titles", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/alternative_titles", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -70,7 +85,7 @@ impl crate::prelude::Command for MovieAlternativeTitles {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieAlternativeTitles;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use chrono::NaiveDate;
 
+const PATH: &str = "/movie/{movie_id}/changes";
+
 /// Command to get changes for a movie
 ///
 /// ```rust
@@ -59,25 +62,31 @@ impl MovieChanges {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieChange {
     pub key: String,
     pub items: Vec<MovieChangeItem>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieChangeItem {
     pub id: String,
     pub action: String,
     pub time: chrono::DateTime<chrono::Utc>,
     pub iso_639_1: String,
     pub iso_3166_1: String,
-    // TODO handle really dynamic kind of values
-    // pub value: String,
-    // pub original_value: String,
+    /// Value after the change, its type depending on the key of the change.
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+    /// Value before the change, only provided for the updates.
+    #[serde(default)]
+    pub original_value: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieChangesResult {
     pub changes: Vec<MovieChange>,
 }
@@ -86,7 +95,17 @@ impl crate::prelude::Command for MovieChanges {
     type Output = MovieChangesResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/changes", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/changes", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -94,7 +113,7 @@ impl crate::prelude::Command for MovieChanges {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieChanges;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::credits::{Cast, Crew};
 
+const PATH: &str = "/movie/{movie_id}/credits";
+
 /// Command to get alternative titles for a movie
 ///
 /// ```rust
@@ -43,10 +46,13 @@ impl MovieCredits {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieCreditsResult {
     pub id: u64,
+    #[serde(default)]
     pub cast: Vec<Cast>,
+    #[serde(default)]
     pub crew: Vec<Crew>,
 }
 
@@ -54,7 +60,17 @@ impl crate::prelude::Command for MovieCredits {
     type Output = MovieCreditsResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/credits", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/credits", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -66,7 +82,7 @@ impl crate::prelude::Command for MovieCredits {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -74,7 +90,7 @@ mod tests {
     use crate::client::Client;
     use crate::prelude::Command;
 
-    use super::MovieCredits;
+    use super::{MovieCredits, MovieCreditsResult};
 
     #[tokio::test]
     async fn it_works() {
@@ -96,6 +112,11 @@ mod tests {
 
         let result = MovieCredits::new(3).execute(&client).await.unwrap();
         assert_eq!(result.id, 550);
+
+        let stored = serde_json::to_string(&result.clone()).unwrap();
+        let restored: MovieCreditsResult = serde_json::from_str(&stored).unwrap();
+        assert_eq!(restored.cast.len(), result.cast.len());
+        assert_eq!(restored.crew.len(), result.crew.len());
     }
 
     #[tokio::test]
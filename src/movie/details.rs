@@ -1,4 +1,10 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::prelude::Command;
+
+const PATH: &str = "/movie/{movie_id}";
 
 /// Command to get the details of a movie
 ///
@@ -39,13 +45,68 @@ impl MovieDetails {
         self.language = value;
         self
     }
+
+    /// Fetches concurrently the details of the movie in each of the given
+    /// languages, ignoring the language of the command, and returns them keyed
+    /// by language. Fails as soon as one of the requests fails.
+    ///
+    /// ```rust
+    /// use tmdb_api::client::Client;
+    /// use tmdb_api::client::reqwest::ReqwestExecutor;
+    /// use tmdb_api::movie::details::MovieDetails;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+    ///     let cmd = MovieDetails::new(550);
+    ///     match cmd.execute_multilang(&client, &["en-US", "fr-FR", "de-DE"]).await {
+    ///         Ok(res) => println!("found: {:#?}", res.get("fr-FR")),
+    ///         Err(err) => eprintln!("error: {:?}", err),
+    ///     };
+    /// }
+    /// ```
+    pub async fn execute_multilang<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+        languages: &[&str],
+    ) -> Result<HashMap<String, super::Movie>, crate::error::Error> {
+        let requests = languages.iter().map(|language| async move {
+            let cmd = Self::new(self.movie_id).with_language(Some(language.to_string()));
+            cmd.execute(client)
+                .await
+                .map(|movie| (language.to_string(), movie))
+        });
+        futures_util::future::try_join_all(requests)
+            .await
+            .map(HashMap::from_iter)
+    }
 }
 
-impl crate::prelude::Command for MovieDetails {
+impl super::MovieShort {
+    /// Fetches the details of the movie.
+    pub async fn details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<super::Movie, crate::error::Error> {
+        MovieDetails::new(self.inner.id).execute(client).await
+    }
+}
+
+impl Command for MovieDetails {
     type Output = super::Movie;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -57,7 +118,7 @@ impl crate::prelude::Command for MovieDetails {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -89,6 +150,60 @@ mod tests {
         assert_eq!(result.inner.id, 550);
     }
 
+    #[tokio::test]
+    async fn multilang_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mut mocks = Vec::new();
+        for language in ["en-US", "fr-FR"] {
+            let mock = server
+                .mock("GET", "/movie/550")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                    Matcher::UrlEncoded("language".into(), language.into()),
+                ]))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(include_str!("../../assets/movie-details.json"))
+                .create_async()
+                .await;
+            mocks.push(mock);
+        }
+
+        let result = MovieDetails::new(550)
+            .execute_multilang(&client, &["en-US", "fr-FR"])
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result["fr-FR"].inner.id, 550);
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+
+        let _missing = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("language".into(), "de-DE".into()),
+            ]))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+        let err = MovieDetails::new(550)
+            .execute_multilang(&client, &["en-US", "de-DE"])
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
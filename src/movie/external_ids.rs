@@ -1,4 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+const PATH: &str = "/movie/{movie_id}/external_ids";
 
 /// Command to get similar movies to a movie
 ///
@@ -31,7 +34,8 @@ impl MovieExternalIds {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieExternalIdsResult {
     pub id: u64,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
@@ -40,13 +44,25 @@ pub struct MovieExternalIdsResult {
     pub facebook_id: Option<String>,
     pub instagram_id: Option<String>,
     pub twitter_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub wikidata_id: Option<String>,
 }
 
 impl crate::prelude::Command for MovieExternalIds {
     type Output = MovieExternalIdsResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/external_ids", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/external_ids", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -54,7 +70,7 @@ impl crate::prelude::Command for MovieExternalIds {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieExternalIds;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::image::Image;
 
+const PATH: &str = "/movie/{movie_id}/images";
+
 /// Get the images that belong to a movie.
 ///
 /// ```rust
@@ -43,11 +46,13 @@ impl MovieImages {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieImagesResult {
     pub id: u64,
     pub backdrops: Vec<Image>,
     pub posters: Vec<Image>,
+    #[serde(default)]
     pub logos: Vec<Image>,
 }
 
@@ -55,7 +60,17 @@ impl crate::prelude::Command for MovieImages {
     type Output = MovieImagesResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/images", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/images", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -67,7 +82,7 @@ impl crate::prelude::Command for MovieImages {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::keyword::Keyword;
 
+const PATH: &str = "/movie/{movie_id}/keywords";
+
 /// Get the keywords that have been added to a movie.
 ///
 /// ```rust
@@ -33,7 +36,8 @@ impl MovieKeywords {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieKeywordsResult {
     pub id: u64,
     pub keywords: Vec<Keyword>,
@@ -43,7 +47,17 @@ impl crate::prelude::Command for MovieKeywords {
     type Output = MovieKeywordsResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/keywords", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/keywords", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -51,7 +65,7 @@ impl crate::prelude::Command for MovieKeywords {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieKeywords;
     use crate::client::reqwest::ReqwestExecutor;
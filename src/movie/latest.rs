@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+const PATH: &str = "/movie/latest";
+
 /// Get the most newly created movie. This is a live response and will continuously change.
 ///
 /// ```rust
@@ -35,7 +37,11 @@ impl crate::prelude::Command for MovieLatest {
     type Output = super::Movie;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/movie/latest")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -47,7 +53,7 @@ impl crate::prelude::Command for MovieLatest {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
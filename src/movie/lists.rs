@@ -1,7 +1,11 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
+use crate::common::list::ListSummary;
 use crate::common::PaginatedResult;
 
+const PATH: &str = "/movie/{movie_id}/lists";
+
 /// Get a list of lists that this movie belongs to.
 ///
 /// ```rust
@@ -51,24 +55,24 @@ impl MovieLists {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct MovieList {
-    pub id: u64,
-    pub name: String,
-    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
-    pub description: Option<String>,
-    pub list_type: String,
-    pub poster_path: Option<String>,
-    pub iso_639_1: String,
-    pub item_count: u64,
-    pub favorite_count: u64,
-}
+/// Kept for compatibility, the lists are the same for movies and TV shows.
+pub type MovieList = ListSummary;
 
 impl crate::prelude::Command for MovieLists {
-    type Output = PaginatedResult<MovieList>;
+    type Output = PaginatedResult<ListSummary>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/lists", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/lists", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -83,7 +87,15 @@ impl crate::prelude::Command for MovieLists {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for MovieLists {
+    type Item = ListSummary;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -21,6 +21,8 @@ pub mod now_playing;
 #[cfg(feature = "commands")]
 pub mod popular;
 #[cfg(feature = "commands")]
+pub mod rating;
+#[cfg(feature = "commands")]
 pub mod recommendations;
 #[cfg(feature = "commands")]
 pub mod release_dates;
@@ -44,21 +46,23 @@ pub mod watch_providers;
 use crate::collection::CollectionBase;
 use crate::common::country::Country;
 use crate::common::language::Language;
+use crate::common::score::OrderedScore;
 use crate::common::status::Status;
+use crate::common::{MediaType, ShortString};
 use crate::company::CompanyShort;
 use crate::genre::Genre;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct MovieBase {
     pub id: u64,
-    pub title: String,
-    pub original_title: String,
-    pub original_language: String,
+    pub title: ShortString,
+    pub original_title: ShortString,
+    pub original_language: ShortString,
     pub overview: String,
     #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
     pub release_date: Option<chrono::NaiveDate>,
-    pub poster_path: Option<String>,
-    pub backdrop_path: Option<String>,
+    pub poster_path: Option<ShortString>,
+    pub backdrop_path: Option<ShortString>,
     pub adult: bool,
     pub popularity: f64,
     pub vote_count: u64,
@@ -66,11 +70,53 @@ pub struct MovieBase {
     pub video: bool,
 }
 
+impl MovieBase {
+    /// Totally ordered `vote_average`, to sort the movies.
+    pub fn ordered_vote_average(&self) -> OrderedScore {
+        OrderedScore(self.vote_average)
+    }
+
+    /// Totally ordered `popularity`, to sort the movies.
+    pub fn ordered_popularity(&self) -> OrderedScore {
+        OrderedScore(self.popularity)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct MovieShort {
     #[serde(flatten)]
     pub inner: MovieBase,
+    #[serde(default)]
     pub genre_ids: Vec<u64>,
+    /// Only provided by the endpoints mixing movies and TV shows, like the
+    /// trending ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<MediaType>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[cfg(feature = "strict")]
+impl crate::common::strict::KnownFields for MovieShort {
+    const FIELDS: &'static [&'static str] = &[
+        "id",
+        "title",
+        "original_title",
+        "original_language",
+        "overview",
+        "release_date",
+        "poster_path",
+        "backdrop_path",
+        "adult",
+        "popularity",
+        "vote_count",
+        "vote_average",
+        "video",
+        "genre_ids",
+        "media_type",
+    ];
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -78,24 +124,123 @@ pub struct Movie {
     #[serde(flatten)]
     pub inner: MovieBase,
     pub budget: u64,
+    #[serde(default)]
     pub genres: Vec<Genre>,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub homepage: Option<String>,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub imdb_id: Option<String>,
     pub belongs_to_collection: Option<CollectionBase>,
+    #[serde(default)]
     pub production_companies: Vec<CompanyShort>,
+    #[serde(default)]
     pub production_countries: Vec<Country>,
     pub revenue: u64,
     pub runtime: Option<u64>,
+    #[serde(default)]
     pub spoken_languages: Vec<Language>,
     pub status: Status,
     pub tagline: Option<String>,
+    /// Fields of the response that aren't supported yet by this crate.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
 
 #[cfg(test)]
 mod tests {
 
+    #[test]
+    fn sort_by_popularity() {
+        let result: crate::common::PaginatedResult<super::MovieShort> =
+            serde_json::from_str(include_str!("../../assets/movie-popular.json")).unwrap();
+        let mut movies = result.results;
+        movies.sort_by_key(|item| std::cmp::Reverse(item.inner.ordered_popularity()));
+        assert!(movies
+            .windows(2)
+            .all(|items| items[0].inner.popularity >= items[1].inner.popularity));
+    }
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[test]
+    fn should_keep_extra_fields() {
+        let mut origin: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/movie-details.json")).unwrap();
+        origin["new_field"] = serde_json::json!(["value"]);
+        let movie: super::Movie = serde_json::from_value(origin).unwrap();
+        assert_eq!(movie.inner.id, 550);
+        assert_eq!(movie.extra["new_field"], serde_json::json!(["value"]));
+        assert!(!movie.extra.contains_key("title"));
+        assert!(!movie.extra.contains_key("budget"));
+        let serial = serde_json::to_value(&movie).unwrap();
+        assert_eq!(serial["new_field"], serde_json::json!(["value"]));
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn should_reject_unknown_fields() {
+        let mut origin: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/movie-details.json")).unwrap();
+        origin["new_field"] = serde_json::json!(["value"]);
+        let err = serde_json::from_value::<super::Movie>(origin).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown field `new_field`"),
+            "{err}"
+        );
+
+        let mut origin: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/movie-popular.json")).unwrap();
+        origin["results"][0]["new_field"] = serde_json::json!(["value"]);
+        let err =
+            serde_json::from_value::<crate::common::PaginatedResult<super::MovieShort>>(origin)
+                .unwrap_err();
+        assert!(
+            err.to_string().contains("unknown field `new_field`"),
+            "{err}"
+        );
+
+        let mut origin: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/account-rated-movies.json")).unwrap();
+        serde_json::from_value::<crate::common::PaginatedResult<crate::account::RatedMovie>>(
+            origin.clone(),
+        )
+        .unwrap();
+        origin["results"][0]["new_field"] = serde_json::json!(["value"]);
+        let err = serde_json::from_value::<
+            crate::common::PaginatedResult<crate::account::RatedMovie>,
+        >(origin)
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("unknown field `new_field`"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn should_list_known_fields() {
+        use crate::common::strict::KnownFields;
+
+        let mut movie: super::MovieShort =
+            serde_json::from_str::<crate::common::PaginatedResult<super::MovieShort>>(
+                include_str!("../../assets/movie-popular.json"),
+            )
+            .unwrap()
+            .results
+            .remove(0);
+        movie.media_type = Some(crate::common::MediaType::Movie);
+        let value = serde_json::to_value(&movie).unwrap();
+        let mut fields = value.as_object().unwrap().keys().collect::<Vec<_>>();
+        fields.sort();
+        let mut expected = super::MovieShort::FIELDS.to_vec();
+        expected.sort();
+        assert_eq!(fields, expected);
+    }
+
     #[test]
     fn serialize() {
         let origin = include_str!("../../assets/movie-details.json");
@@ -1,8 +1,10 @@
-use chrono::NaiveDate;
 use std::borrow::Cow;
 
+pub use crate::common::DateRange;
 use crate::common::PaginatedResult;
 
+const PATH: &str = "/movie/now_playing";
+
 /// Get a list of movies in theatres. This is a release type query that looks for
 /// all movies that have a release type of 2 or 3 within the specified date range.
 ///
@@ -52,26 +54,26 @@ impl MovieNowPlaying {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct DateRange {
-    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
-    pub maximum: Option<NaiveDate>,
-    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
-    pub minimum: Option<NaiveDate>,
-}
-
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MovieNowPlayingResult {
     #[serde(flatten)]
     pub inner: PaginatedResult<super::MovieShort>,
     pub dates: DateRange,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
 
 impl crate::prelude::Command for MovieNowPlaying {
     type Output = MovieNowPlayingResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/movie/now_playing")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -89,7 +91,7 @@ impl crate::prelude::Command for MovieNowPlaying {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieNowPlaying;
     use crate::client::reqwest::ReqwestExecutor;
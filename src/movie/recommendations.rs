@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::PaginatedResult;
 
+const PATH: &str = "/movie/{movie_id}/recommendations";
+
 /// Get a list of recommended movies for a movie.
 ///
 /// ```rust
@@ -55,7 +58,17 @@ impl crate::prelude::Command for MovieRecommendations {
     type Output = PaginatedResult<super::MovieShort>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/recommendations", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/recommendations", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -70,7 +83,15 @@ impl crate::prelude::Command for MovieRecommendations {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for MovieRecommendations {
+    type Item = super::MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieRecommendations;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::release_date::LocatedReleaseDates;
 
+const PATH: &str = "/movie/{movie_id}/release_dates";
+
 /// Get the release date along with the certification for a movie.
 ///
 /// ```rust
@@ -33,7 +36,8 @@ impl MovieReleaseDates {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieReleaseDatesResult {
     pub id: u64,
     pub results: Vec<LocatedReleaseDates>,
@@ -43,7 +47,17 @@ impl crate::prelude::Command for MovieReleaseDates {
     type Output = MovieReleaseDatesResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/release_dates", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/release_dates", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -51,7 +65,7 @@ impl crate::prelude::Command for MovieReleaseDates {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieReleaseDates;
     use crate::client::reqwest::ReqwestExecutor;
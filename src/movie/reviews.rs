@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::PaginatedResult;
 
+const PATH: &str = "/movie/{movie_id}/reviews";
+
 /// Get the release date along with the certification for a movie.
 ///
 /// ```rust
@@ -33,15 +36,10 @@ impl MovieReviews {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AuthorDetails {
-    pub name: String,
-    pub username: String,
-    pub avatar_path: Option<String>,
-    pub rating: Option<f32>,
-}
+pub use crate::review::AuthorDetails;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieReview {
     pub id: String,
     pub author: String,
@@ -56,7 +54,17 @@ impl crate::prelude::Command for MovieReviews {
     type Output = PaginatedResult<MovieReview>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/reviews", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/reviews", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -64,7 +72,7 @@ impl crate::prelude::Command for MovieReviews {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieReviews;
     use crate::client::reqwest::ReqwestExecutor;
@@ -88,6 +88,10 @@ impl crate::prelude::Command for MovieSearch {
         Cow::Borrowed(PATH)
     }
 
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
         let mut res = vec![("query", Cow::Borrowed(self.query.as_str()))];
 
@@ -117,7 +121,15 @@ impl crate::prelude::Command for MovieSearch {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for MovieSearch {
+    type Item = super::MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieSearch;
     use crate::client::reqwest::ReqwestExecutor;
@@ -153,7 +165,7 @@ mod tests {
         assert!(result.total_pages > 0);
         assert!(result.total_results > 0);
         let item = result.results.first().unwrap();
-        assert_eq!(item.inner.title, "RRRrrrr!!!");
+        assert_eq!(&*item.inner.title, "RRRrrrr!!!");
     }
 
     #[tokio::test]
@@ -278,7 +290,7 @@ mod integration_tests {
         assert_eq!(result.total_pages, 1);
         assert_eq!(result.total_results, 1);
         let item = result.results.first().unwrap();
-        assert_eq!(item.inner.title, "RRRrrrr!!!");
+        assert_eq!(&*item.inner.title, "RRRrrrr!!!");
     }
 
     #[tokio::test]
@@ -1,4 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+const PATH: &str = "/movie/{movie_id}/similar";
 
 /// Command to get similar movies to a movie
 ///
@@ -53,7 +56,17 @@ impl crate::prelude::Command for GetSimilarMovies {
     type Output = crate::common::PaginatedResult<super::MovieShort>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/similar", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/similar", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -70,7 +83,15 @@ impl crate::prelude::Command for GetSimilarMovies {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for GetSimilarMovies {
+    type Item = super::MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::GetSimilarMovies;
     use crate::client::reqwest::ReqwestExecutor;
@@ -103,7 +124,7 @@ mod tests {
         assert!(result.total_pages > 0);
         assert!(result.total_results > 0);
         let item = result.results.first().unwrap();
-        assert_eq!(item.inner.title, "The Asphalt Jungle");
+        assert_eq!(&*item.inner.title, "The Asphalt Jungle");
     }
 
     #[tokio::test]
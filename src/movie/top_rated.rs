@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use crate::common::PaginatedResult;
 
+const PATH: &str = "/movie/top_rated";
+
 /// Get a list of the current popular movies on TMDB. This list updates daily.
 ///
 /// ```rust
@@ -51,7 +53,11 @@ impl crate::prelude::Command for MovieTopRated {
     type Output = PaginatedResult<super::MovieShort>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/movie/top_rated")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -69,7 +75,15 @@ impl crate::prelude::Command for MovieTopRated {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for MovieTopRated {
+    type Item = super::MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieTopRated;
     use crate::client::reqwest::ReqwestExecutor;
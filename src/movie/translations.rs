@@ -1,4 +1,9 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::translation::TranslationsResult;
+
+const PATH: &str = "/movie/{movie_id}/translations";
 
 /// Get a list of translations that have been created for a movie.
 ///
@@ -31,36 +36,40 @@ impl MovieTranslations {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct TranslationData {
-    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
     pub title: Option<String>,
-    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
     pub overview: Option<String>,
-    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub tagline: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
     pub homepage: Option<String>,
+    /// Runtime in minutes, not provided for all the translations.
+    #[serde(default)]
+    pub runtime: Option<u64>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Translation {
-    pub iso_3166_1: String,
-    pub iso_639_1: String,
-    pub name: String,
-    pub english_name: String,
-    pub data: TranslationData,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct MovieTranslationsResult {
-    pub id: u64,
-    pub translations: Vec<Translation>,
-}
+pub type Translation = crate::common::translation::Translation<TranslationData>;
+pub type MovieTranslationsResult = TranslationsResult<TranslationData>;
 
 impl crate::prelude::Command for MovieTranslations {
     type Output = MovieTranslationsResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/translations", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/translations", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -68,7 +77,7 @@ impl crate::prelude::Command for MovieTranslations {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieTranslations;
     use crate::client::reqwest::ReqwestExecutor;
@@ -97,6 +106,9 @@ mod tests {
         let result = MovieTranslations::new(550).execute(&client).await.unwrap();
         assert_eq!(result.id, 550);
         assert!(!result.translations.is_empty());
+        let english = result.find("en", Some("US")).unwrap();
+        assert_eq!(english.data.title, None);
+        assert!(english.data.tagline.is_some());
     }
 
     #[tokio::test]
@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use crate::common::PaginatedResult;
 
+const PATH: &str = "/movie/upcoming";
+
 /// Get a list of upcoming movies in theatres. This is a release type query that looks
 /// for all movies that have a release type of 2 or 3 within the specified date range.
 ///
@@ -55,7 +57,11 @@ impl crate::prelude::Command for MovieUpcoming {
     type Output = PaginatedResult<super::MovieShort>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/movie/upcoming")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -73,7 +79,15 @@ impl crate::prelude::Command for MovieUpcoming {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for MovieUpcoming {
+    type Item = super::MovieShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieUpcoming;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::video::Video;
 
+const PATH: &str = "/movie/{movie_id}/videos";
+
 /// Get a list of recommended movies for a movie.
 ///
 /// ```rust
@@ -43,7 +46,8 @@ impl MovieVideos {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct MovieVideosResult {
     pub id: u64,
     pub results: Vec<Video>,
@@ -53,7 +57,17 @@ impl crate::prelude::Command for MovieVideos {
     type Output = MovieVideosResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/movie/{}/videos", self.movie_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/movie/{}/videos", self.movie_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -65,7 +79,7 @@ impl crate::prelude::Command for MovieVideos {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::MovieVideos;
     use crate::client::reqwest::ReqwestExecutor;
@@ -0,0 +1,231 @@
+//! Helpers to walk through the pages of a paginated command.
+//!
+//! The [Paginator] keeps track of the last page that has been successfully
+//! fetched in a [Checkpoint]. When fetching a page fails, the checkpoint is left
+//! untouched so that the same page can be retried, or the checkpoint can be
+//! persisted and used later to resume the pull where it stopped.
+
+use crate::client::{Client, Executor};
+use crate::common::PaginatedResult;
+use crate::prelude::Command;
+
+/// Command returning a single page of a paginated list.
+pub trait PaginatedCommand: Command<Output = PaginatedResult<Self::Item>> + Sync {
+    type Item;
+
+    /// Sets the page the command should query.
+    fn set_page(&mut self, page: u32);
+}
+
+/// State of a paginated pull.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Checkpoint {
+    /// Last page that has been fetched successfully, `0` when nothing was fetched yet.
+    pub last_page: u32,
+    /// Total number of pages, as announced by the last fetched page.
+    pub total_pages: Option<u64>,
+}
+
+impl Checkpoint {
+    /// Creates a checkpoint resuming after the given page.
+    pub fn after(last_page: u32) -> Self {
+        Self {
+            last_page,
+            total_pages: None,
+        }
+    }
+
+    /// Page that will be fetched next.
+    pub fn next_page(&self) -> u32 {
+        self.last_page + 1
+    }
+
+    /// Whether all the pages have been fetched.
+    pub fn is_done(&self) -> bool {
+        self.total_pages
+            .is_some_and(|total| u64::from(self.last_page) >= total)
+    }
+}
+
+/// Drives a paginated command page by page.
+///
+/// ```rust
+/// use tmdb_api::changes::list::ChangeList;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::pagination::Paginator;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let mut paginator = Paginator::new(ChangeList::movie());
+///     loop {
+///         match paginator.next_page(&client).await {
+///             Ok(Some(page)) => println!("found: {:#?}", page.results),
+///             Ok(None) => break,
+///             Err(err) => {
+///                 // the checkpoint can be stored to resume the pull later on
+///                 eprintln!("error: {:?}, stopped at {:?}", err, paginator.checkpoint());
+///                 break;
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Paginator<C> {
+    command: C,
+    checkpoint: Checkpoint,
+}
+
+impl<C: PaginatedCommand> Paginator<C> {
+    pub fn new(command: C) -> Self {
+        Self::resume(command, Checkpoint::default())
+    }
+
+    /// Creates a paginator starting right after the page stored in the checkpoint.
+    pub fn resume(command: C, checkpoint: Checkpoint) -> Self {
+        Self {
+            command,
+            checkpoint,
+        }
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.checkpoint
+    }
+
+    /// Last page that has been fetched successfully.
+    pub fn last_page(&self) -> Option<u32> {
+        Some(self.checkpoint.last_page).filter(|page| *page > 0)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.checkpoint.is_done()
+    }
+
+    pub fn into_inner(self) -> C {
+        self.command
+    }
+
+    /// Fetches the next page, returning `None` once all the pages have been fetched.
+    ///
+    /// When the request fails, the checkpoint is not updated and calling this method
+    /// again will retry the same page.
+    pub async fn next_page<E: Executor>(
+        &mut self,
+        client: &Client<E>,
+    ) -> Result<Option<PaginatedResult<C::Item>>, crate::error::Error> {
+        if self.is_done() {
+            return Ok(None);
+        }
+        let page = self.checkpoint.next_page();
+        self.command.set_page(page);
+        let result = self.command.execute(client).await?;
+        self.checkpoint = Checkpoint {
+            last_page: page,
+            total_pages: Some(result.total_pages),
+        };
+        Ok(Some(result))
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::changes::list::ChangeList;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+
+    use super::{Checkpoint, Paginator};
+
+    const LAST_PAGE: &str =
+        r#"{"page":2,"total_pages":2,"total_results":1,"results":[{"id":42,"adult":false}]}"#;
+
+    #[test]
+    fn checkpoint_is_done() {
+        assert!(!Checkpoint::default().is_done());
+        assert!(!Checkpoint::after(3).is_done());
+        assert!(!Checkpoint {
+            last_page: 1,
+            total_pages: Some(2)
+        }
+        .is_done());
+        assert!(Checkpoint {
+            last_page: 2,
+            total_pages: Some(2)
+        }
+        .is_done());
+        assert!(Checkpoint {
+            last_page: 0,
+            total_pages: Some(0)
+        }
+        .is_done());
+    }
+
+    #[tokio::test]
+    async fn resume_after_error() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _first = server
+            .mock("GET", "/movie/changes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"page":1,"total_pages":2,"total_results":1,"results":[{"id":1,"adult":false}]}"#,
+            )
+            .create_async()
+            .await;
+        let failing = server
+            .mock("GET", "/movie/changes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let mut paginator = Paginator::new(ChangeList::movie());
+        assert_eq!(paginator.last_page(), None);
+        let page = paginator.next_page(&client).await.unwrap().unwrap();
+        assert_eq!(page.page, 1);
+        assert_eq!(paginator.last_page(), Some(1));
+
+        assert!(paginator.next_page(&client).await.is_err());
+        let checkpoint = paginator.checkpoint();
+        assert_eq!(checkpoint.last_page, 1);
+        assert_eq!(checkpoint.next_page(), 2);
+        failing.remove_async().await;
+
+        let _second = server
+            .mock("GET", "/movie/changes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(LAST_PAGE)
+            .create_async()
+            .await;
+
+        let mut paginator = Paginator::resume(ChangeList::movie(), checkpoint);
+        let page = paginator.next_page(&client).await.unwrap().unwrap();
+        assert_eq!(page.page, 2);
+        assert!(paginator.is_done());
+        assert!(paginator.next_page(&client).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use chrono::NaiveDate;
+
+const PATH: &str = "/person/{person_id}/changes";
+
+/// Command to get changes for a person
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::people::changes::PersonChanges;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = PersonChanges::new(287);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PersonChanges {
+    /// ID of the person
+    pub person_id: u64,
+    /// Filter the results with a start date.
+    pub start_date: Option<NaiveDate>,
+    /// Filter the results with a end date.
+    pub end_date: Option<NaiveDate>,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+}
+
+impl PersonChanges {
+    pub fn new(person_id: u64) -> Self {
+        Self {
+            person_id,
+            start_date: None,
+            end_date: None,
+            page: None,
+        }
+    }
+
+    pub fn with_start_date(mut self, value: Option<NaiveDate>) -> Self {
+        self.start_date = value;
+        self
+    }
+
+    pub fn with_end_date(mut self, value: Option<NaiveDate>) -> Self {
+        self.end_date = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonChange {
+    pub key: String,
+    pub items: Vec<PersonChangeItem>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonChangeItem {
+    pub id: String,
+    pub action: String,
+    pub time: chrono::DateTime<chrono::Utc>,
+    /// Not provided for the changes on images.
+    #[serde(default)]
+    pub iso_639_1: Option<String>,
+    #[serde(default)]
+    pub iso_3166_1: Option<String>,
+    /// Value after the change, its type depending on the key of the change.
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+    /// Value before the change, only provided for the updates.
+    #[serde(default)]
+    pub original_value: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonChangesResult {
+    pub changes: Vec<PersonChange>,
+}
+
+impl crate::prelude::Command for PersonChanges {
+    type Output = PersonChangesResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/person/{}/changes", self.person_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref start_date) = self.start_date {
+            res.push(("start_date", Cow::Owned(start_date.to_string())));
+        }
+        if let Some(ref end_date) = self.end_date {
+            res.push(("end_date", Cow::Owned(end_date.to_string())));
+        }
+        if let Some(ref page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        res
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::PersonChanges;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/changes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("start_date".into(), "2024-01-10".into()),
+                Matcher::UrlEncoded("end_date".into(), "2024-01-20".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/person-single-changes.json"))
+            .create_async()
+            .await;
+
+        let result = PersonChanges::new(287)
+            .with_start_date(chrono::NaiveDate::from_ymd_opt(2024, 1, 10))
+            .with_end_date(chrono::NaiveDate::from_ymd_opt(2024, 1, 20))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.changes.len(), 2);
+        assert_eq!(result.changes[0].key, "biography");
+        assert_eq!(result.changes[0].items[0].iso_639_1.as_deref(), Some("en"));
+        assert_eq!(result.changes[1].items[0].iso_639_1, None);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/changes")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = PersonChanges::new(287).execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/changes")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = PersonChanges::new(287).execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use super::PersonChanges;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let _result = PersonChanges::new(287)
+            .with_start_date(Some(chrono::NaiveDate::from_ymd_opt(2024, 1, 10).unwrap()))
+            .with_end_date(Some(chrono::NaiveDate::from_ymd_opt(2024, 1, 20).unwrap()))
+            .execute(&client)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::movie::MovieShort;
+use crate::tvshow::TVShowShort;
+
+const PATH: &str = "/person/{person_id}/combined_credits";
+
+/// Command to get the movie and TV show credits of a person
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::people::combined_credits::PersonCombinedCredits;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = PersonCombinedCredits::new(287);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PersonCombinedCredits {
+    /// ID of the person
+    pub person_id: u64,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+}
+
+impl PersonCombinedCredits {
+    pub fn new(person_id: u64) -> Self {
+        Self {
+            person_id,
+            language: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+}
+
+/// Movie or TV show a person has been credited for.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum CreditMedia {
+    Movie(MovieShort),
+    Tv(TVShowShort),
+}
+
+impl CreditMedia {
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::Movie(inner) => inner.inner.id,
+            Self::Tv(inner) => inner.inner.id,
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        match self {
+            Self::Movie(inner) => &inner.inner.title,
+            Self::Tv(inner) => &inner.inner.name,
+        }
+    }
+
+    /// Release date of a movie or first air date of a TV show.
+    pub fn date(&self) -> Option<chrono::NaiveDate> {
+        match self {
+            Self::Movie(inner) => inner.inner.release_date,
+            Self::Tv(inner) => inner.inner.first_air_date,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CombinedCast {
+    #[serde(flatten)]
+    pub media: CreditMedia,
+    pub credit_id: String,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub character: Option<String>,
+    /// Only provided for movies.
+    #[serde(default)]
+    pub order: Option<u64>,
+    /// Only provided for TV shows.
+    #[serde(default)]
+    pub episode_count: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CombinedCrew {
+    #[serde(flatten)]
+    pub media: CreditMedia,
+    pub credit_id: String,
+    pub department: String,
+    pub job: String,
+    /// Only provided for TV shows.
+    #[serde(default)]
+    pub episode_count: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonCombinedCreditsResult {
+    pub id: u64,
+    #[serde(default)]
+    pub cast: Vec<CombinedCast>,
+    #[serde(default)]
+    pub crew: Vec<CombinedCrew>,
+}
+
+impl crate::prelude::Command for PersonCombinedCredits {
+    type Output = PersonCombinedCreditsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/person/{}/combined_credits", self.person_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        if let Some(language) = self.language.as_ref() {
+            vec![("language", Cow::Borrowed(language.as_str()))]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::{CreditMedia, PersonCombinedCredits};
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/combined_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/person-combined-credits.json"))
+            .create_async()
+            .await;
+
+        let result = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 287);
+        assert_eq!(result.cast[0].media.title(), "Fight Club");
+        assert_eq!(result.cast[0].order, Some(1));
+        let friends = result
+            .cast
+            .iter()
+            .find(|item| matches!(item.media, CreditMedia::Tv(_)))
+            .unwrap();
+        assert_eq!(friends.media.id(), 1668);
+        assert_eq!(friends.episode_count, Some(1));
+        assert_eq!(result.crew[0].job, "Producer");
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/combined_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/combined_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::PersonCombinedCredits;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 287);
+    }
+}
@@ -1,4 +1,9 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::prelude::Command;
+
+const PATH: &str = "/person/{person_id}";
 
 /// Command to get the details of a person
 ///
@@ -41,11 +46,51 @@ impl PersonDetails {
     }
 }
 
-impl crate::prelude::Command for PersonDetails {
+impl super::PersonShort {
+    /// Fetches the details of the person.
+    pub async fn details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<super::Person, crate::error::Error> {
+        PersonDetails::new(self.id).execute(client).await
+    }
+}
+
+impl crate::common::credits::Cast {
+    /// Fetches the details of the credited person.
+    pub async fn person_details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<super::Person, crate::error::Error> {
+        self.person.details(client).await
+    }
+}
+
+impl crate::common::credits::Crew {
+    /// Fetches the details of the credited person.
+    pub async fn person_details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<super::Person, crate::error::Error> {
+        self.person.details(client).await
+    }
+}
+
+impl Command for PersonDetails {
     type Output = super::Person;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/person/{}", self.person_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/person/{}", self.person_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -57,7 +102,7 @@ impl crate::prelude::Command for PersonDetails {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -89,6 +134,35 @@ mod tests {
         assert_eq!(result.inner.id, 287);
     }
 
+    #[tokio::test]
+    async fn cast_person_details_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/person-details.json"))
+            .create_async()
+            .await;
+
+        let credits: crate::movie::credits::MovieCreditsResult =
+            serde_json::from_str(include_str!("../../assets/movie-credits.json")).unwrap();
+        let cast = credits
+            .cast
+            .iter()
+            .find(|item| item.person.id == 287)
+            .unwrap();
+        let result = cast.person_details(&client).await.unwrap();
+        assert_eq!(result.inner.id, 287);
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
@@ -0,0 +1,234 @@
+//! Timeline of the credits of a person, built from the combined credits.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::Datelike;
+
+use super::combined_credits::{CreditMedia, PersonCombinedCreditsResult};
+
+const ACTING_DEPARTMENT: &str = "Acting";
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FilmographyJob {
+    pub department: String,
+    pub job: String,
+}
+
+/// All the credits of a person for a single movie or TV show.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FilmographyEntry {
+    pub media: CreditMedia,
+    /// Whether the person is part of the cast.
+    pub acting: bool,
+    pub characters: Vec<String>,
+    pub jobs: Vec<FilmographyJob>,
+    /// Number of episodes the person has been credited for, for TV shows.
+    pub episode_count: Option<u64>,
+}
+
+impl FilmographyEntry {
+    fn new(media: CreditMedia) -> Self {
+        Self {
+            media,
+            acting: false,
+            characters: Vec::new(),
+            jobs: Vec::new(),
+            episode_count: None,
+        }
+    }
+
+    fn add_episode_count(&mut self, value: Option<u64>) {
+        self.episode_count = self.episode_count.max(value);
+    }
+
+    pub fn date(&self) -> Option<chrono::NaiveDate> {
+        self.media.date()
+    }
+
+    /// First year of the decade of the entry, like `1990`.
+    pub fn decade(&self) -> Option<i32> {
+        self.date()
+            .map(|date| date.year() - date.year().rem_euclid(10))
+    }
+
+    /// Departments the person has been credited in, `Acting` for the cast.
+    pub fn departments(&self) -> Vec<&str> {
+        let mut res: Vec<&str> = Vec::new();
+        if self.acting {
+            res.push(ACTING_DEPARTMENT);
+        }
+        for job in self.jobs.iter() {
+            if !res.contains(&job.department.as_str()) {
+                res.push(job.department.as_str());
+            }
+        }
+        res
+    }
+}
+
+/// Credits of a person merged per movie or TV show and sorted by date, the
+/// entries without date coming last.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::people::combined_credits::PersonCombinedCredits;
+/// use tmdb_api::people::filmography::Filmography;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = PersonCombinedCredits::new(287);
+///     match cmd.execute(&client).await {
+///         Ok(res) => {
+///             let filmography = Filmography::from(res);
+///             for (decade, entries) in filmography.by_decade() {
+///                 println!("{:?}: {} titles", decade, entries.len());
+///             }
+///         }
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Filmography {
+    pub entries: Vec<FilmographyEntry>,
+}
+
+impl From<PersonCombinedCreditsResult> for Filmography {
+    fn from(value: PersonCombinedCreditsResult) -> Self {
+        let mut entries: Vec<FilmographyEntry> = Vec::new();
+        let mut indexes: HashMap<(bool, u64), usize> = HashMap::new();
+        let mut entry = |media: CreditMedia| -> usize {
+            let key = (matches!(media, CreditMedia::Movie(_)), media.id());
+            *indexes.entry(key).or_insert_with(|| {
+                entries.push(FilmographyEntry::new(media));
+                entries.len() - 1
+            })
+        };
+
+        let mut cast_indexes = Vec::with_capacity(value.cast.len());
+        for item in value.cast {
+            let index = entry(item.media);
+            cast_indexes.push((index, item.character, item.episode_count));
+        }
+        let mut crew_indexes = Vec::with_capacity(value.crew.len());
+        for item in value.crew {
+            let index = entry(item.media);
+            let job = FilmographyJob {
+                department: item.department,
+                job: item.job,
+            };
+            crew_indexes.push((index, job, item.episode_count));
+        }
+
+        for (index, character, episode_count) in cast_indexes {
+            let entry = &mut entries[index];
+            entry.acting = true;
+            if let Some(character) = character {
+                if !entry.characters.contains(&character) {
+                    entry.characters.push(character);
+                }
+            }
+            entry.add_episode_count(episode_count);
+        }
+        for (index, job, episode_count) in crew_indexes {
+            let entry = &mut entries[index];
+            if !entry.jobs.contains(&job) {
+                entry.jobs.push(job);
+            }
+            entry.add_episode_count(episode_count);
+        }
+
+        entries.sort_by_key(|item| (item.date().is_none(), item.date()));
+        Self { entries }
+    }
+}
+
+impl Filmography {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries grouped by department, `Acting` being used for the cast.
+    pub fn by_department(&self) -> BTreeMap<&str, Vec<&FilmographyEntry>> {
+        let mut res: BTreeMap<&str, Vec<&FilmographyEntry>> = BTreeMap::new();
+        for entry in self.entries.iter() {
+            for department in entry.departments() {
+                res.entry(department).or_default().push(entry);
+            }
+        }
+        res
+    }
+
+    /// Entries grouped by the first year of their decade, the entries without
+    /// date being grouped under `None`.
+    pub fn by_decade(&self) -> BTreeMap<Option<i32>, Vec<&FilmographyEntry>> {
+        let mut res: BTreeMap<Option<i32>, Vec<&FilmographyEntry>> = BTreeMap::new();
+        for entry in self.entries.iter() {
+            res.entry(entry.decade()).or_default().push(entry);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::people::combined_credits::PersonCombinedCreditsResult;
+
+    use super::Filmography;
+
+    fn filmography() -> Filmography {
+        let result: PersonCombinedCreditsResult =
+            serde_json::from_str(include_str!("../../assets/person-combined-credits.json"))
+                .unwrap();
+        Filmography::from(result)
+    }
+
+    #[test]
+    fn should_merge_and_sort() {
+        let filmography = filmography();
+        let titles: Vec<_> = filmography
+            .entries
+            .iter()
+            .map(|item| item.media.title())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Friends",
+                "Se7en",
+                "Fight Club",
+                "Moneyball",
+                "12 Years a Slave",
+                "F1"
+            ]
+        );
+        let moneyball = &filmography.entries[3];
+        assert!(moneyball.acting);
+        assert_eq!(moneyball.characters, vec!["Billy Beane"]);
+        assert_eq!(moneyball.jobs[0].job, "Producer");
+        assert_eq!(moneyball.departments(), vec!["Acting", "Production"]);
+        assert_eq!(filmography.entries[0].episode_count, Some(1));
+    }
+
+    #[test]
+    fn should_group() {
+        let filmography = filmography();
+        let by_department = filmography.by_department();
+        assert_eq!(by_department["Acting"].len(), 6);
+        assert_eq!(by_department["Production"].len(), 3);
+
+        let by_decade = filmography.by_decade();
+        let decades: Vec<_> = by_decade
+            .iter()
+            .map(|(decade, entries)| (*decade, entries.len()))
+            .collect();
+        assert_eq!(decades, vec![(None, 1), (Some(1990), 3), (Some(2010), 2)]);
+    }
+}
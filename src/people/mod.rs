@@ -1,13 +1,23 @@
 #[cfg(feature = "commands")]
+pub mod changes;
+#[cfg(feature = "commands")]
+pub mod combined_credits;
+#[cfg(feature = "commands")]
 pub mod details;
+#[cfg(feature = "commands")]
+pub mod filmography;
+#[cfg(feature = "commands")]
+pub mod translations;
+
+use crate::common::ShortString;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct PersonShort {
     pub id: u64,
     pub credit_id: Option<String>,
-    pub name: String,
+    pub name: ShortString,
     pub gender: Option<u64>,
-    pub profile_path: Option<String>,
+    pub profile_path: Option<ShortString>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -15,6 +25,7 @@ pub struct Person {
     #[serde(flatten)]
     pub inner: PersonShort,
     pub adult: bool,
+    #[serde(default)]
     pub also_known_as: Vec<String>,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub biography: Option<String>,
@@ -28,4 +39,12 @@ pub struct Person {
     pub popularity: f64,
     pub place_of_birth: Option<String>,
     pub profile_path: Option<String>,
+    /// Fields of the response that aren't supported yet by this crate.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
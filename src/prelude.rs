@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use crate::client::Executor;
+use crate::client::{Executor, Method};
 
 #[async_trait::async_trait]
 pub trait Command {
@@ -9,10 +9,118 @@ pub trait Command {
     fn path(&self) -> Cow<'static, str>;
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)>;
 
+    /// Template of the path of the command, with the names of its parameters
+    /// between braces, like `/movie/{movie_id}/credits`, to identify its
+    /// endpoint whatever the values of the parameters.
+    ///
+    /// Defaults to `"unknown"` for the commands implemented outside of the
+    /// crate that don't provide it.
+    fn path_template(&self) -> &'static str {
+        "unknown"
+    }
+
+    /// Writes the path of the command at the end of the url being built.
+    ///
+    /// The commands with a dynamic path override it to write the path without
+    /// formatting an intermediate string, it has to match [Command::path].
+    fn write_path(&self, out: &mut String) {
+        out.push_str(&self.path());
+    }
+
+    /// HTTP method of the command, only the commands writing data override it.
+    fn method(&self) -> Method {
+        Method::Get
+    }
+
+    /// JSON body sent with the command, if any.
+    fn body(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Access token of the user, only provided by the commands of the v4 API.
+    ///
+    /// Those commands write their path from the root of the API, like `/4/list`,
+    /// and get authenticated with the token on top of the api key.
+    fn access_token(&self) -> Option<&str> {
+        None
+    }
+
     async fn execute<E: Executor + Send + Sync>(
         &self,
         client: &crate::Client<E>,
     ) -> Result<Self::Output, crate::error::Error> {
-        client.execute(self.path().as_ref(), self.params()).await
+        client.execute_command(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+
+    /// Whether the path has the segments of the template, its parameters
+    /// matching any segment.
+    fn matches_template(path: &str, template: &str) -> bool {
+        let path = path.split('/').collect::<Vec<_>>();
+        let template = template.split('/').collect::<Vec<_>>();
+        path.len() == template.len()
+            && path.iter().zip(template.iter()).all(|(value, expected)| {
+                (expected.starts_with('{') && expected.ends_with('}') && !value.is_empty())
+                    || value == expected
+            })
+    }
+
+    fn assert_path<C: Command>(command: C, expected: &str) {
+        let mut path = String::new();
+        command.write_path(&mut path);
+        assert_eq!(path, expected);
+        assert_eq!(command.path(), expected);
+        assert!(
+            matches_template(&path, command.path_template()),
+            "{path} doesn't match {}",
+            command.path_template()
+        );
+    }
+
+    #[test]
+    fn should_match_path_template() {
+        use crate::common::MediaType;
+
+        assert_path(
+            crate::movie::popular::MoviePopular::default(),
+            "/movie/popular",
+        );
+        assert_path(
+            crate::movie::search::MovieSearch::new("alien".into()),
+            "/search/movie",
+        );
+        assert_path(
+            crate::movie::credits::MovieCredits::new(550),
+            "/movie/550/credits",
+        );
+        assert_path(
+            crate::tvshow::episode::rating::TVShowEpisodeAddRating::new(
+                1399,
+                1,
+                2,
+                crate::common::rating::Rating::new(8.5).unwrap(),
+            ),
+            "/tv/1399/season/1/episode/2/rating",
+        );
+        assert_path(
+            crate::account::v4::rated::AccountRatedTVShows::new("token".into(), "abcd".into()),
+            "/4/account/abcd/tv/rated",
+        );
+        assert_path(
+            crate::list::v4::items::ListAddItems::new("token".into(), 14, Vec::new()),
+            "/4/list/14/items",
+        );
+        assert_path(
+            crate::changes::list::ChangeList::person(),
+            "/person/changes",
+        );
+        assert_path(
+            crate::watch_provider::list::WatchProviderList::new(MediaType::Tv),
+            "/watch/providers/tv",
+        );
     }
 }
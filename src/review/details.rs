@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::prelude::Command;
+
+const PATH: &str = "/review/{review_id}";
+
+/// Command to get the details of a review, as listed in the reviews of a
+/// movie or a TV show.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::review::details::ReviewDetails;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ReviewDetails::new("5b1c13b9c3a36848f2026384");
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReviewDetails {
+    /// ID of the review
+    pub review_id: String,
+}
+
+impl ReviewDetails {
+    pub fn new<I: Into<String>>(review_id: I) -> Self {
+        Self {
+            review_id: review_id.into(),
+        }
+    }
+}
+
+impl Command for ReviewDetails {
+    type Output = super::Review;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/review/{}", self.review_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::MediaType;
+    use crate::prelude::Command;
+
+    use super::ReviewDetails;
+
+    const REVIEW_ID: &str = "5b1c13b9c3a36848f2026384";
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/review/5b1c13b9c3a36848f2026384")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/review-details.json"))
+            .create_async()
+            .await;
+
+        let result = ReviewDetails::new(REVIEW_ID)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, REVIEW_ID);
+        assert_eq!(result.iso_639_1, "en");
+        assert_eq!(result.media_id, 550);
+        assert!(matches!(result.media_type, MediaType::Movie));
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/review/5b1c13b9c3a36848f2026384")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = ReviewDetails::new(REVIEW_ID)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/review/5b1c13b9c3a36848f2026384")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ReviewDetails::new(REVIEW_ID)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::ReviewDetails;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = ReviewDetails::new("5b1c13b9c3a36848f2026384")
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.media_id, 550);
+    }
+}
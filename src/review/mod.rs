@@ -0,0 +1,30 @@
+#[cfg(feature = "commands")]
+pub mod details;
+
+use crate::common::MediaType;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AuthorDetails {
+    pub name: String,
+    pub username: String,
+    pub avatar_path: Option<String>,
+    pub rating: Option<f32>,
+}
+
+/// Full review, along with the media it has been written for.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Review {
+    pub id: String,
+    pub author: String,
+    pub author_details: AuthorDetails,
+    pub content: String,
+    pub url: String,
+    pub iso_639_1: String,
+    pub media_id: u64,
+    pub media_title: String,
+    pub media_type: MediaType,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,8 @@
+//! Search across movies, TV shows and people at once.
+
+#[cfg(feature = "commands")]
+pub mod multi;
+
+/// Movie, TV show or person found by a multi search, tagged by its
+/// `media_type` like the trending results.
+pub type MultiSearchResult = crate::trending::TrendingResult;
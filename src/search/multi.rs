@@ -0,0 +1,272 @@
+use std::borrow::Cow;
+
+use super::MultiSearchResult;
+
+const PATH: &str = "/search/multi";
+
+/// Command to search for movies, TV shows and people in a single request
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::search::multi::MultiSearch;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = MultiSearch::new("pitt".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MultiSearch {
+    /// Text query to search.
+    pub query: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Which page to query.
+    pub page: Option<u32>,
+    /// Whether to include adult (pornography) content in the results.
+    pub include_adult: bool,
+    /// ISO 3166-1 code to filter release region. Must be uppercase.
+    pub region: Option<String>,
+}
+
+impl MultiSearch {
+    pub fn new(query: String) -> Self {
+        Self {
+            query,
+            language: None,
+            page: None,
+            include_adult: false,
+            region: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_include_adult(mut self, value: bool) -> Self {
+        self.include_adult = value;
+        self
+    }
+
+    pub fn with_region(mut self, value: Option<String>) -> Self {
+        self.region = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for MultiSearch {
+    type Output = crate::common::PaginatedResult<MultiSearchResult>;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = vec![("query", Cow::Borrowed(self.query.as_str()))];
+
+        if let Some(language) = self.language.as_ref() {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        if self.include_adult {
+            res.push(("include_adult", Cow::Borrowed("true")));
+        }
+        if let Some(region) = self.region.as_ref() {
+            res.push(("region", Cow::Borrowed(region.as_str())));
+        }
+
+        res
+    }
+}
+
+impl crate::pagination::PaginatedCommand for MultiSearch {
+    type Item = MultiSearchResult;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+    use crate::search::MultiSearchResult;
+
+    use super::MultiSearch;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let cmd = MultiSearch::new("pitt".into())
+            .with_language(Some("en-US".into()))
+            .with_page(Some(1))
+            .with_include_adult(true)
+            .with_region(Some("US".into()));
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("query".into(), "pitt".into()),
+                Matcher::UrlEncoded("language".into(), "en-US".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+                Matcher::UrlEncoded("include_adult".into(), "true".into()),
+                Matcher::UrlEncoded("region".into(), "US".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/search-multi.json"))
+            .create_async()
+            .await;
+        let result = cmd.execute(&client).await.unwrap();
+        assert_eq!(result.page, 1);
+        assert_eq!(result.total_pages, 2);
+        let kinds: Vec<_> = result
+            .results
+            .iter()
+            .map(|item| match item {
+                MultiSearchResult::Movie(_) => "movie",
+                MultiSearchResult::Tv(_) => "tv",
+                MultiSearchResult::Person(_) => "person",
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec!["movie", "tv", "person", "movie", "tv", "movie", "tv"]
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let cmd = MultiSearch::new("Whatever".into());
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("query".into(), "Whatever".into()),
+            ]))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+        let err = cmd.execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let cmd = MultiSearch::new("Whatever".into());
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("query".into(), "Whatever".into()),
+            ]))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+        let err = cmd.execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+
+    #[tokio::test]
+    async fn validation_error() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let cmd = MultiSearch::new("".into());
+
+        let _m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("query".into(), "".into()),
+            ]))
+            .with_status(422)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/validation-error.json"))
+            .create_async()
+            .await;
+        let err = cmd.execute(&client).await.unwrap_err();
+        let validation_err = err.as_validation_error().unwrap();
+        assert_eq!(validation_err.errors.len(), 1);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::MultiSearch;
+
+    #[tokio::test]
+    async fn search_pitt() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = MultiSearch::new("pitt".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert!(!result.results.is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+//! Compares live responses with the fixtures of the `assets` directory.
+//!
+//! Running `cargo test --features integration snapshot` reports the fields that
+//! appeared or disappeared in the live responses. When `TMDB_UPDATE_SNAPSHOTS`
+//! is set, the fixtures are overwritten with the live responses instead.
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+/// Keys whose values are replaced before a response is stored.
+const SECRET_KEYS: &[&str] = &["api_key", "session_id", "guest_session_id", "request_token"];
+
+/// Replaces the secret values of a response, whether it's a known secret key
+/// or the given token appearing in a string.
+fn scrub(value: &mut Value, token: &str) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if SECRET_KEYS.contains(&key.as_str()) {
+                    *item = Value::String("redacted".into());
+                } else {
+                    scrub(item, token);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| scrub(item, token)),
+        Value::String(inner) if !token.is_empty() && inner.contains(token) => {
+            *inner = inner.replace(token, "redacted");
+        }
+        _ => {}
+    }
+}
+
+/// Lists the fields of a response with their type. Null values are only listed
+/// by their path, given that TMDB makes most of the fields nullable.
+fn schema(value: &Value, path: &str, output: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter() {
+                let path = format!("{path}.{key}");
+                output.insert(path.clone());
+                schema(item, &path, output);
+            }
+        }
+        Value::Array(items) => {
+            let path = format!("{path}[]");
+            items.iter().for_each(|item| schema(item, &path, output));
+        }
+        Value::Null => {}
+        Value::Bool(_) => {
+            output.insert(format!("{path}: bool"));
+        }
+        Value::Number(_) => {
+            output.insert(format!("{path}: number"));
+        }
+        Value::String(_) => {
+            output.insert(format!("{path}: string"));
+        }
+    }
+}
+
+/// Fields added and removed in the live response, compared to the fixture.
+fn drift(fixture: &Value, live: &Value) -> (Vec<String>, Vec<String>) {
+    let mut expected = BTreeSet::new();
+    schema(fixture, "", &mut expected);
+    let mut found = BTreeSet::new();
+    schema(live, "", &mut found);
+    (
+        found.difference(&expected).cloned().collect(),
+        expected.difference(&found).cloned().collect(),
+    )
+}
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("assets")
+        .join(format!("{name}.json"))
+}
+
+mod tests {
+    use serde_json::json;
+
+    #[test]
+    fn should_scrub_secrets() {
+        let mut value = json!({
+            "session_id": "abcd",
+            "items": [{"link": "https://example.com/?api_key=my-token"}],
+            "name": "Fight Club",
+        });
+        super::scrub(&mut value, "my-token");
+        assert_eq!(
+            value,
+            json!({
+                "session_id": "redacted",
+                "items": [{"link": "https://example.com/?api_key=redacted"}],
+                "name": "Fight Club",
+            })
+        );
+    }
+
+    #[test]
+    fn should_detect_drift() {
+        let fixture =
+            json!({"id": 1, "title": "Fight Club", "tagline": null, "genres": [{"id": 18}]});
+        let live = json!({"id": 1, "title": null, "tagline": "Mischief", "genres": [{"id": "18", "name": "Drama"}]});
+        let (added, removed) = super::drift(&fixture, &live);
+        assert_eq!(
+            added,
+            vec![
+                ".genres[].id: string",
+                ".genres[].name",
+                ".genres[].name: string",
+                ".tagline: string"
+            ]
+        );
+        assert_eq!(removed, vec![".genres[].id: number", ".title: string"]);
+    }
+
+    #[test]
+    fn fixtures_exist() {
+        for (name, _, _) in super::integration_tests::requests() {
+            assert!(super::fixture_path(name).exists(), "missing fixture {name}");
+        }
+    }
+}
+
+mod integration_tests {
+    use std::borrow::Cow;
+
+    use crate::prelude::Command;
+
+    type Request = (&'static str, Cow<'static, str>, Vec<(&'static str, String)>);
+
+    fn request<C: Command>(name: &'static str, command: C) -> Request {
+        let params = command
+            .params()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_owned()))
+            .collect();
+        (name, command.path(), params)
+    }
+
+    /// Requests matching the fixtures that don't require a session.
+    pub(super) fn requests() -> Vec<Request> {
+        use crate::collection::details::CollectionDetails;
+        use crate::company::details::CompanyDetails;
+        use crate::discover::movie::MovieDiscover;
+        use crate::discover::tvshow::TVShowDiscover;
+        use crate::discover::{Filter, MovieSortField, SortBy, TVShowSortField};
+        use crate::find::by_id::FindById;
+        use crate::genre::list::GenreList;
+        use crate::movie::credits::MovieCredits;
+        use crate::movie::details::MovieDetails;
+        use crate::people::details::PersonDetails;
+        use crate::tvshow::aggregate_credits::TVShowAggregateCredits;
+        use crate::tvshow::airing_today::TVShowAiringToday;
+        use crate::tvshow::details::TVShowDetails;
+        use crate::tvshow::on_the_air::TVShowOnTheAir;
+
+        vec![
+            request("collection-details", CollectionDetails::new(10)),
+            request("company-details", CompanyDetails::new(1)),
+            request(
+                "discover-movie",
+                MovieDiscover::default()
+                    .with_genres(Some(Filter::all([28, 12])))
+                    .with_sort_by(Some(SortBy::desc(MovieSortField::Popularity))),
+            ),
+            request(
+                "discover-tv",
+                TVShowDiscover::default()
+                    .with_genres(Some(Filter::one(18)))
+                    .with_sort_by(Some(SortBy::desc(TVShowSortField::Popularity))),
+            ),
+            request("find-imdb", FindById::imdb_id("tt0137523")),
+            request("genre-movie-list", GenreList::movie()),
+            request("genre-tv-list", GenreList::tv()),
+            request("movie-credits", MovieCredits::new(550)),
+            request("movie-details", MovieDetails::new(550)),
+            request("person-details", PersonDetails::new(287)),
+            request("tv-aggregate-credits", TVShowAggregateCredits::new(1399)),
+            request("tv-airing-today", TVShowAiringToday::default()),
+            request("tv-details", TVShowDetails::new(1399)),
+            request("tv-on-the-air", TVShowOnTheAir::default()),
+        ]
+    }
+
+    #[cfg(feature = "integration")]
+    #[tokio::test]
+    async fn snapshot() {
+        use crate::client::reqwest::ReqwestExecutor;
+        use crate::client::Client;
+
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let update = std::env::var("TMDB_UPDATE_SNAPSHOTS").is_ok();
+        let client = Client::<ReqwestExecutor>::new(secret.clone());
+
+        let mut failures = Vec::new();
+        for (name, path, params) in requests() {
+            let params = params
+                .iter()
+                .map(|(key, value)| (*key, Cow::Borrowed(value.as_str())))
+                .collect();
+            let mut live: serde_json::Value = client.execute(&path, params).await.unwrap();
+            super::scrub(&mut live, &secret);
+
+            let fixture_path = super::fixture_path(name);
+            if update {
+                let content = serde_json::to_string_pretty(&live).unwrap();
+                std::fs::write(&fixture_path, content + "\n").unwrap();
+                continue;
+            }
+
+            let fixture = std::fs::read_to_string(&fixture_path).unwrap();
+            let fixture: serde_json::Value = serde_json::from_str(&fixture).unwrap();
+            let (added, removed) = super::drift(&fixture, &live);
+            if !added.is_empty() || !removed.is_empty() {
+                failures.push(format!("{name}: added {added:?}, removed {removed:?}"));
+            }
+        }
+        assert!(
+            failures.is_empty(),
+            "schema drift:\n{}",
+            failures.join("\n")
+        );
+    }
+}
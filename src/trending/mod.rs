@@ -0,0 +1,65 @@
+//! Trending movies, TV shows and people, for the day or the week.
+
+#[cfg(feature = "commands")]
+pub mod all;
+#[cfg(feature = "commands")]
+pub mod movie;
+#[cfg(feature = "commands")]
+pub mod people;
+#[cfg(feature = "commands")]
+pub mod tvshow;
+
+use crate::movie::MovieShort;
+use crate::people::PersonShort;
+use crate::tvshow::TVShowShort;
+
+/// Period over which the trends are computed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TimeWindow {
+    #[default]
+    Day,
+    Week,
+}
+
+impl TimeWindow {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+}
+
+impl std::fmt::Display for TimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Trending movie, TV show or person, tagged by its `media_type`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum TrendingResult {
+    Movie(MovieShort),
+    Tv(TVShowShort),
+    Person(PersonShort),
+}
+
+impl TrendingResult {
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::Movie(inner) => inner.inner.id,
+            Self::Tv(inner) => inner.inner.id,
+            Self::Person(inner) => inner.id,
+        }
+    }
+
+    /// Title of a movie or name of a TV show or person.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Movie(inner) => &inner.inner.title,
+            Self::Tv(inner) => &inner.inner.name,
+            Self::Person(inner) => &inner.name,
+        }
+    }
+}
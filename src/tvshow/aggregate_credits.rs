@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::credits::{AggregateCast, AggregateCrew};
+
+const PATH: &str = "/tv/{tv_id}/aggregate_credits";
+
+/// Command to get the aggregate credits of a TV show, grouping the roles and jobs
+/// of each person over all the seasons and episodes.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::aggregate_credits::TVShowAggregateCredits;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowAggregateCredits::new(1);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowAggregateCredits {
+    /// ID of the TV show
+    pub tv_id: u64,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+}
+
+impl TVShowAggregateCredits {
+    pub fn new(tv_id: u64) -> Self {
+        Self {
+            tv_id,
+            language: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TVShowAggregateCreditsResult {
+    pub id: u64,
+    #[serde(default)]
+    pub cast: Vec<AggregateCast>,
+    #[serde(default)]
+    pub crew: Vec<AggregateCrew>,
+}
+
+impl crate::prelude::Command for TVShowAggregateCredits {
+    type Output = TVShowAggregateCreditsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/aggregate_credits", self.tv_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        if let Some(ref language) = self.language {
+            vec![("language", Cow::Borrowed(language))]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowAggregateCredits;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/aggregate_credits")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("language".into(), "en-US".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-aggregate-credits.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowAggregateCredits::new(1399)
+            .with_language(Some("en-US".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 1399);
+        let bran = result
+            .cast
+            .iter()
+            .find(|item| item.person.id == 3903)
+            .unwrap();
+        assert_eq!(bran.roles.len(), 2);
+        assert_eq!(bran.total_episode_count, 46);
+        assert_eq!(result.crew[0].jobs[0].job, "Executive Producer");
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/aggregate_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowAggregateCredits::new(1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/aggregate_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowAggregateCredits::new(1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowAggregateCredits;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = TVShowAggregateCredits::new(1399)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 1399);
+    }
+}
@@ -0,0 +1,203 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::common::country::CountryCode;
+
+const PATH: &str = "/tv/{tv_id}/content_ratings";
+
+/// Command to get the content ratings of a TV show, per country.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::content_rating::TVShowContentRatings;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowContentRatings::new(1);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res.for_country("US")),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowContentRatings {
+    /// ID of the TV show
+    pub tv_id: u64,
+}
+
+impl TVShowContentRatings {
+    pub fn new(tv_id: u64) -> Self {
+        Self { tv_id }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ContentRating {
+    pub iso_3166_1: CountryCode,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub rating: Option<String>,
+    /// Reasons of the rating, like `Violence`, in the language of the country.
+    #[serde(default)]
+    pub descriptors: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TVShowContentRatingsResult {
+    pub id: u64,
+    #[serde(default)]
+    pub results: Vec<ContentRating>,
+}
+
+impl TVShowContentRatingsResult {
+    /// Finds the rating for the given ISO 3166-1 country code, ignoring the case.
+    pub fn for_country<C: AsRef<str>>(&self, country: C) -> Option<&ContentRating> {
+        let country = country.as_ref();
+        self.results.iter().find(|item| item.iso_3166_1 == country)
+    }
+}
+
+impl crate::prelude::Command for TVShowContentRatings {
+    type Output = TVShowContentRatingsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/content_ratings", self.tv_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::country::CountryCode;
+    use crate::prelude::Command;
+
+    use super::TVShowContentRatings;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/content_ratings")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-content-ratings.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowContentRatings::new(1399)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 1399);
+        let au = result.for_country("au").unwrap();
+        assert_eq!(au.rating.as_deref(), Some("MA 15+"));
+        assert_eq!(au.descriptors, vec!["Violence", "Sex", "Language"]);
+        let nl = result.for_country(CountryCode::new("NL")).unwrap();
+        assert!(nl.rating.is_none());
+        assert!(result.for_country("JP").is_none());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/content_ratings")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowContentRatings::new(1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/content_ratings")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowContentRatings::new(1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowContentRatings;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = TVShowContentRatings::new(1399)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 1399);
+        assert!(result.for_country("US").is_some());
+    }
+}
@@ -1,4 +1,9 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::prelude::Command;
+
+const PATH: &str = "/tv/{tv_id}";
 
 /// Command to get the details of a tvshow
 ///
@@ -41,11 +46,31 @@ impl TVShowDetails {
     }
 }
 
-impl crate::prelude::Command for TVShowDetails {
+impl super::TVShowShort {
+    /// Fetches the details of the TV show.
+    pub async fn details<E: crate::client::Executor>(
+        &self,
+        client: &crate::Client<E>,
+    ) -> Result<super::TVShow, crate::error::Error> {
+        TVShowDetails::new(self.inner.id).execute(client).await
+    }
+}
+
+impl Command for TVShowDetails {
     type Output = super::TVShow;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/tv/{}", self.tv_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}", self.tv_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -57,7 +82,7 @@ impl crate::prelude::Command for TVShowDetails {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::TVShowDetails;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,4 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+const PATH: &str = "/tv/{tv_id}/season/{season_number}/episode/{episode_number}";
 
 /// Command to get the details of a tvshow episode
 ///
@@ -51,10 +54,21 @@ impl crate::prelude::Command for TVShowEpisodeDetails {
     type Output = crate::tvshow::Episode;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!(
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(
+            out,
             "/tv/{}/season/{}/episode/{}",
             self.tv_id, self.season_number, self.episode_number
-        ))
+        );
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -66,7 +80,7 @@ impl crate::prelude::Command for TVShowEpisodeDetails {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::TVShowEpisodeDetails;
     use crate::client::reqwest::ReqwestExecutor;
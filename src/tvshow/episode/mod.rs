@@ -1,2 +1,8 @@
 #[cfg(feature = "commands")]
+pub mod account_states;
+#[cfg(feature = "commands")]
+pub mod changes;
+#[cfg(feature = "commands")]
 pub mod details;
+#[cfg(feature = "commands")]
+pub mod rating;
@@ -0,0 +1,163 @@
+//! Alternative orders of the episodes of a TV show, like the DVD or the absolute
+//! order.
+
+#[cfg(feature = "commands")]
+pub mod details;
+
+use std::collections::HashMap;
+
+use crate::company::CompanyShort;
+
+use super::{Episode, EpisodeShort, Season};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum EpisodeGroupType {
+    OriginalAirDate = 1,
+    Absolute = 2,
+    Dvd = 3,
+    Digital = 4,
+    StoryArc = 5,
+    Production = 6,
+    Tv = 7,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EpisodeGroupEpisode {
+    #[serde(flatten)]
+    pub inner: EpisodeShort,
+    /// Position of the episode in its group, starting at 0.
+    pub order: u64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EpisodeGroup {
+    pub id: String,
+    pub name: String,
+    /// Position of the group, 0 being usually used for the specials.
+    pub order: u64,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub episodes: Vec<EpisodeGroupEpisode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EpisodeGroupDetails {
+    pub id: String,
+    pub name: String,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub episode_count: u64,
+    pub group_count: u64,
+    #[serde(rename = "type")]
+    pub kind: EpisodeGroupType,
+    pub network: Option<CompanyShort>,
+    #[serde(default)]
+    pub groups: Vec<EpisodeGroup>,
+}
+
+/// Season of a TV show in the order of an episode group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderedSeason<T> {
+    /// Order of the group.
+    pub season_number: u64,
+    pub name: String,
+    pub episodes: Vec<OrderedEpisode<T>>,
+}
+
+/// Episode of a TV show in the order of an episode group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderedEpisode<T> {
+    /// Position of the episode in its group, starting at 1.
+    pub episode_number: u64,
+    pub episode: T,
+}
+
+impl EpisodeGroupDetails {
+    /// Reorders the given episodes, matched by ID, in the seasons and the order
+    /// of the group. The episodes of the group that are not given are skipped.
+    pub fn reorder<'a, I>(&self, episodes: I) -> Vec<OrderedSeason<&'a Episode>>
+    where
+        I: IntoIterator<Item = &'a Episode>,
+    {
+        let episodes: HashMap<u64, &'a Episode> = episodes
+            .into_iter()
+            .map(|episode| (episode.inner.id, episode))
+            .collect();
+
+        let mut groups: Vec<&EpisodeGroup> = self.groups.iter().collect();
+        groups.sort_by_key(|group| group.order);
+        groups
+            .into_iter()
+            .map(|group| {
+                let mut items: Vec<&EpisodeGroupEpisode> = group.episodes.iter().collect();
+                items.sort_by_key(|item| item.order);
+                OrderedSeason {
+                    season_number: group.order,
+                    name: group.name.clone(),
+                    episodes: items
+                        .into_iter()
+                        .filter_map(|item| {
+                            episodes.get(&item.inner.id).map(|episode| OrderedEpisode {
+                                episode_number: item.order + 1,
+                                episode: *episode,
+                            })
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Same as [EpisodeGroupDetails::reorder] with the episodes of the given
+    /// seasons.
+    pub fn reorder_seasons<'a, I>(&self, seasons: I) -> Vec<OrderedSeason<&'a Episode>>
+    where
+        I: IntoIterator<Item = &'a Season>,
+    {
+        self.reorder(
+            seasons
+                .into_iter()
+                .flat_map(|season| season.episodes.iter()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EpisodeGroupDetails, EpisodeGroupType};
+
+    #[test]
+    fn should_reorder_episodes() {
+        let group: EpisodeGroupDetails = serde_json::from_str(include_str!(
+            "../../../assets/tv-episode-group-details.json"
+        ))
+        .unwrap();
+        assert_eq!(group.kind, EpisodeGroupType::Production);
+        let season: crate::tvshow::Season =
+            serde_json::from_str(include_str!("../../../assets/tv-season-details.json")).unwrap();
+
+        let seasons = group.reorder_seasons([&season]);
+        assert_eq!(seasons.len(), 2);
+        assert_eq!(seasons[0].season_number, 1);
+        assert_eq!(seasons[0].name, "Part 1");
+        let numbers: Vec<_> = seasons[0]
+            .episodes
+            .iter()
+            .map(|item| (item.episode_number, item.episode.inner.episode_number))
+            .collect();
+        assert_eq!(numbers, vec![(1, 2), (2, 1), (3, 3), (4, 4), (5, 5)]);
+        assert_eq!(seasons[1].episodes[0].episode.inner.episode_number, 6);
+
+        let seasons = group.reorder(season.episodes.iter().take(3));
+        assert_eq!(seasons[0].episodes.len(), 3);
+        assert!(seasons[1].episodes.is_empty());
+    }
+}
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use crate::common::image::Image;
 
+const PATH: &str = "/tv/{tvshow_id}/images";
+
 /// Get the images that belong to a show.
 ///
 /// ```rust
@@ -43,11 +46,13 @@ impl TVShowImages {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct TVShowImagesResult {
     pub id: u64,
     pub backdrops: Vec<Image>,
     pub posters: Vec<Image>,
+    #[serde(default)]
     pub logos: Vec<Image>,
 }
 
@@ -55,7 +60,17 @@ impl crate::prelude::Command for TVShowImages {
     type Output = TVShowImagesResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/tv/{}/images", self.tvshow_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/images", self.tvshow_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -67,7 +82,7 @@ impl crate::prelude::Command for TVShowImages {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::TVShowImages;
     use crate::client::reqwest::ReqwestExecutor;
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+const PATH: &str = "/tv/latest";
+
 /// Get the most newly created show. This is a live response and will continuously change.
 ///
 /// ```rust
@@ -35,7 +37,11 @@ impl crate::prelude::Command for TVShowLatest {
     type Output = super::TVShow;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/tv/latest")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -47,7 +53,7 @@ impl crate::prelude::Command for TVShowLatest {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::TVShowLatest;
     use crate::client::reqwest::ReqwestExecutor;
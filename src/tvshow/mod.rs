@@ -1,23 +1,48 @@
 #[cfg(feature = "commands")]
+pub mod aggregate_credits;
+#[cfg(feature = "commands")]
+pub mod airing_today;
+#[cfg(feature = "commands")]
+pub mod changes;
+#[cfg(feature = "commands")]
+pub mod content_rating;
+#[cfg(feature = "commands")]
+pub mod credits;
+#[cfg(feature = "commands")]
 pub mod details;
 #[cfg(feature = "commands")]
 pub mod images;
 #[cfg(feature = "commands")]
 pub mod latest;
 #[cfg(feature = "commands")]
+pub mod lists;
+#[cfg(feature = "commands")]
+pub mod on_the_air;
+#[cfg(feature = "commands")]
 pub mod popular;
 #[cfg(feature = "commands")]
+pub mod rating;
+#[cfg(feature = "commands")]
 pub mod search;
 #[cfg(feature = "commands")]
 pub mod similar;
 #[cfg(feature = "commands")]
+pub mod translations;
+#[cfg(feature = "commands")]
+pub mod videos;
+#[cfg(feature = "commands")]
 pub mod watch_providers;
 
 pub mod episode;
+pub mod episode_group;
 pub mod season;
 
 use crate::common::country::Country;
+use crate::common::credits::{Cast, Crew};
 use crate::common::language::Language;
+use crate::common::lazy::Lazy;
+use crate::common::score::OrderedScore;
+use crate::common::{MediaType, ShortString};
 use crate::company::CompanyShort;
 use crate::genre::Genre;
 use crate::people::PersonShort;
@@ -25,18 +50,19 @@ use crate::people::PersonShort;
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct TVShowBase {
     pub id: u64,
-    pub name: String,
-    pub original_name: String,
-    pub original_language: String,
-    pub origin_country: Vec<String>,
+    pub name: ShortString,
+    pub original_name: ShortString,
+    pub original_language: ShortString,
+    #[serde(default)]
+    pub origin_country: Vec<ShortString>,
     #[serde(default)]
     pub overview: Option<String>,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub first_air_date: Option<chrono::NaiveDate>,
     #[serde(default)]
-    pub poster_path: Option<String>,
+    pub poster_path: Option<ShortString>,
     #[serde(default)]
-    pub backdrop_path: Option<String>,
+    pub backdrop_path: Option<ShortString>,
     pub popularity: f64,
     pub vote_count: u64,
     pub vote_average: f64,
@@ -44,23 +70,83 @@ pub struct TVShowBase {
     pub adult: bool,
 }
 
+impl TVShowBase {
+    /// Totally ordered `vote_average`, to sort the TV shows.
+    pub fn ordered_vote_average(&self) -> OrderedScore {
+        OrderedScore(self.vote_average)
+    }
+
+    /// Totally ordered `popularity`, to sort the TV shows.
+    pub fn ordered_popularity(&self) -> OrderedScore {
+        OrderedScore(self.popularity)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct TVShowShort {
     #[serde(flatten)]
     pub inner: TVShowBase,
+    #[serde(default)]
     pub genre_ids: Vec<u64>,
+    /// Only provided by the endpoints mixing movies and TV shows, like the
+    /// trending ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<MediaType>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+#[cfg(feature = "strict")]
+impl crate::common::strict::KnownFields for TVShowShort {
+    const FIELDS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "original_name",
+        "original_language",
+        "origin_country",
+        "overview",
+        "first_air_date",
+        "poster_path",
+        "backdrop_path",
+        "popularity",
+        "vote_count",
+        "vote_average",
+        "adult",
+        "genre_ids",
+        "media_type",
+    ];
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EpisodeType {
+    Standard,
+    Finale,
+    MidSeason,
+    /// Type not known by this version of the crate.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct EpisodeShort {
     pub air_date: chrono::NaiveDate,
     pub episode_number: u64,
+    #[serde(default)]
+    pub episode_type: Option<EpisodeType>,
     pub id: u64,
     pub name: String,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub overview: Option<String>,
     pub production_code: String,
+    #[serde(default)]
+    pub runtime: Option<u64>,
     pub season_number: u64,
+    /// ID of the TV show of the episode.
+    #[serde(default)]
+    pub show_id: Option<u64>,
     pub still_path: Option<String>,
     pub vote_average: f64,
     pub vote_count: u64,
@@ -71,8 +157,18 @@ pub struct Episode {
     #[serde(flatten)]
     pub inner: EpisodeShort,
     //
-    pub crew: Vec<PersonShort>,
-    pub guest_stars: Vec<PersonShort>,
+    #[serde(default)]
+    pub crew: Vec<Crew>,
+    #[serde(default)]
+    pub guest_stars: Vec<Cast>,
+    /// Fields of the response that aren't supported yet by this crate.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -85,6 +181,8 @@ pub struct SeasonBase {
     pub overview: Option<String>,
     pub poster_path: Option<String>,
     pub season_number: u64,
+    #[serde(default)]
+    pub vote_average: Option<f64>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -93,6 +191,10 @@ pub struct SeasonShort {
     pub inner: SeasonBase,
     //
     pub episode_count: u64,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -101,34 +203,216 @@ pub struct Season {
     #[serde(flatten)]
     pub inner: SeasonBase,
     pub episodes: Vec<Episode>,
+    /// Fields of the response that aren't supported yet by this crate.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+impl EpisodeShort {
+    /// Whether the episode is the last one of its season or of the show.
+    pub fn is_finale(&self) -> bool {
+        matches!(self.episode_type, Some(EpisodeType::Finale))
+    }
+}
+
+impl Season {
+    /// Sum of the runtimes of the episodes, in minutes, ignoring the episodes
+    /// without runtime.
+    pub fn total_runtime(&self) -> u64 {
+        self.total_runtime_or(0)
+    }
+
+    /// Sum of the runtimes of the episodes, in minutes, using `fallback` for the
+    /// episodes without runtime.
+    pub fn total_runtime_or(&self, fallback: u64) -> u64 {
+        self.episodes
+            .iter()
+            .map(|episode| episode.inner.runtime.unwrap_or(fallback))
+            .sum()
+    }
+}
+
+/// Same as [Season], parsing the episodes with their credits only on demand.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LazySeason {
+    pub _id: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub air_date: Option<chrono::NaiveDate>,
+    pub id: u64,
+    pub name: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub overview: Option<String>,
+    pub poster_path: Option<String>,
+    pub season_number: u64,
+    #[serde(default)]
+    pub vote_average: Option<f64>,
+    pub episodes: Lazy<Vec<Episode>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct TVShow {
     #[serde(flatten)]
     pub inner: TVShowBase,
+    #[serde(default)]
     pub created_by: Vec<PersonShort>,
+    #[serde(default)]
     pub episode_run_time: Vec<u64>,
+    #[serde(default)]
     pub genres: Vec<Genre>,
     pub homepage: String,
     pub in_production: bool,
+    #[serde(default)]
     pub languages: Vec<String>,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub last_air_date: Option<chrono::NaiveDate>,
     pub last_episode_to_air: Option<EpisodeShort>,
     pub next_episode_to_air: Option<EpisodeShort>,
+    #[serde(default)]
     pub networks: Vec<CompanyShort>,
     /// Unlikely to be `None` but found with 81040.
     /// In this case, could be computed by summing the `episodes_count` of the `seasons` field.
     pub number_of_episodes: Option<u64>,
     pub number_of_seasons: u64,
+    #[serde(default)]
     pub production_companies: Vec<CompanyShort>,
+    #[serde(default)]
     pub production_countries: Vec<Country>,
+    #[serde(default)]
     pub seasons: Vec<SeasonShort>,
+    #[serde(default)]
     pub spoken_languages: Vec<Language>,
     pub status: String,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub tagline: Option<String>,
     #[serde(rename = "type")]
     pub ttype: String,
+    /// Fields of the response that aren't supported yet by this crate.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
+}
+
+impl TVShow {
+    /// Number of episodes, computed from the seasons, excluding the specials,
+    /// when `number_of_episodes` is missing.
+    pub fn computed_number_of_episodes(&self) -> u64 {
+        self.number_of_episodes.unwrap_or_else(|| {
+            self.seasons
+                .iter()
+                .filter(|season| season.inner.season_number > 0)
+                .map(|season| season.episode_count)
+                .sum()
+        })
+    }
+
+    /// Average runtime of an episode, in minutes, from `episode_run_time` or the
+    /// last aired episode when it's empty.
+    pub fn average_episode_run_time(&self) -> Option<u64> {
+        if self.episode_run_time.is_empty() {
+            self.last_episode_to_air
+                .as_ref()
+                .or(self.next_episode_to_air.as_ref())
+                .and_then(|episode| episode.runtime)
+        } else {
+            let total: u64 = self.episode_run_time.iter().sum();
+            Some(total / self.episode_run_time.len() as u64)
+        }
+    }
+
+    /// Estimation of the runtime of all the episodes, in minutes. The exact value
+    /// can be computed by summing [Season::total_runtime] for each season.
+    pub fn estimated_total_runtime(&self) -> Option<u64> {
+        self.average_episode_run_time()
+            .map(|runtime| runtime * self.computed_number_of_episodes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "strict")]
+    #[test]
+    fn should_list_known_fields() {
+        use crate::common::strict::KnownFields;
+
+        let mut show: super::TVShowShort = serde_json::from_str::<
+            crate::common::PaginatedResult<super::TVShowShort>,
+        >(include_str!("../../assets/tv-popular.json"))
+        .unwrap()
+        .results
+        .remove(0);
+        show.media_type = Some(crate::common::MediaType::Tv);
+        let value = serde_json::to_value(&show).unwrap();
+        let mut fields = value.as_object().unwrap().keys().collect::<Vec<_>>();
+        fields.sort();
+        let mut expected = super::TVShowShort::FIELDS.to_vec();
+        expected.sort();
+        assert_eq!(fields, expected);
+
+        let mut origin: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/account-rated-tv.json")).unwrap();
+        origin["results"][0]["new_field"] = serde_json::json!(["value"]);
+        let err = serde_json::from_value::<
+            crate::common::PaginatedResult<crate::account::RatedTVShow>,
+        >(origin)
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("unknown field `new_field`"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn episode_type() {
+        let season: super::Season =
+            serde_json::from_str(include_str!("../../assets/tv-season-details.json")).unwrap();
+        let finales: Vec<_> = season
+            .episodes
+            .iter()
+            .filter(|episode| episode.inner.is_finale())
+            .map(|episode| episode.inner.episode_number)
+            .collect();
+        assert_eq!(finales, vec![10]);
+        assert_eq!(
+            season.episodes[0].inner.episode_type,
+            Some(super::EpisodeType::Standard)
+        );
+        assert_eq!(season.episodes[0].inner.show_id, Some(1399));
+
+        let unknown: super::EpisodeType = serde_json::from_str("\"season_premiere\"").unwrap();
+        assert_eq!(unknown, super::EpisodeType::Unknown);
+    }
+
+    #[test]
+    fn season_total_runtime() {
+        let mut season: super::Season =
+            serde_json::from_str(include_str!("../../assets/tv-season-details.json")).unwrap();
+        assert_eq!(season.total_runtime(), 567);
+        season.episodes[0].inner.runtime = None;
+        assert_eq!(season.total_runtime(), 505);
+        assert_eq!(season.total_runtime_or(60), 565);
+    }
+
+    #[test]
+    fn tvshow_estimated_total_runtime() {
+        let mut tvshow: super::TVShow =
+            serde_json::from_str(include_str!("../../assets/tv-details.json")).unwrap();
+        assert_eq!(tvshow.computed_number_of_episodes(), 73);
+        assert_eq!(tvshow.average_episode_run_time(), Some(80));
+        assert_eq!(tvshow.estimated_total_runtime(), Some(73 * 80));
+
+        tvshow.number_of_episodes = None;
+        tvshow.episode_run_time = vec![50, 60];
+        assert_eq!(tvshow.computed_number_of_episodes(), 73);
+        assert_eq!(tvshow.estimated_total_runtime(), Some(73 * 55));
+    }
 }
@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use crate::common::PaginatedResult;
 
+const PATH: &str = "/tv/popular";
+
 /// Get a list of the current popular movies on TMDB. This list updates daily.
 ///
 /// ```rust
@@ -44,7 +46,11 @@ impl crate::prelude::Command for TVShowPopular {
     type Output = PaginatedResult<super::TVShowShort>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Borrowed("/tv/popular")
+        Cow::Borrowed(PATH)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -59,7 +65,15 @@ impl crate::prelude::Command for TVShowPopular {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for TVShowPopular {
+    type Item = super::TVShowShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::TVShowPopular;
     use crate::client::reqwest::ReqwestExecutor;
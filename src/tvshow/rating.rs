@@ -0,0 +1,312 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::authentication::client::SessionCommand;
+use crate::client::Method;
+use crate::common::rating::Rating;
+use crate::common::StatusResult;
+use crate::prelude::Command;
+
+const PATH: &str = "/tv/{tv_id}/rating";
+
+/// Command to rate a TV show, with a user or a guest session.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::rating::Rating;
+/// use tmdb_api::tvshow::rating::TVShowAddRating;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowAddRating::new(1399, Rating::new(8.5).unwrap())
+///         .with_session_id(Some("my-session-id".into()));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TVShowAddRating {
+    /// ID of the TV show
+    pub tv_id: u64,
+    pub rating: Rating,
+    pub session_id: Option<String>,
+    pub guest_session_id: Option<String>,
+}
+
+impl TVShowAddRating {
+    pub fn new(tv_id: u64, rating: Rating) -> Self {
+        Self {
+            tv_id,
+            rating,
+            session_id: None,
+            guest_session_id: None,
+        }
+    }
+
+    pub fn with_session_id(mut self, value: Option<String>) -> Self {
+        self.session_id = value;
+        self
+    }
+
+    pub fn with_guest_session_id(mut self, value: Option<String>) -> Self {
+        self.guest_session_id = value;
+        self
+    }
+}
+
+impl Command for TVShowAddRating {
+    type Output = StatusResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/rating", self.tv_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        crate::util::session_params(self.session_id.as_deref(), self.guest_session_id.as_deref())
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "value": self.rating }))
+    }
+}
+
+impl SessionCommand for TVShowAddRating {
+    fn set_session_id(&mut self, value: Option<String>) {
+        self.session_id = value;
+    }
+
+    fn set_guest_session_id(&mut self, value: Option<String>) {
+        self.guest_session_id = value;
+    }
+}
+
+/// Command to remove the rating of a TV show, with a user or a guest session.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::rating::TVShowDeleteRating;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowDeleteRating::new(1399).with_session_id(Some("my-session-id".into()));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowDeleteRating {
+    /// ID of the TV show
+    pub tv_id: u64,
+    pub session_id: Option<String>,
+    pub guest_session_id: Option<String>,
+}
+
+impl TVShowDeleteRating {
+    pub fn new(tv_id: u64) -> Self {
+        Self {
+            tv_id,
+            session_id: None,
+            guest_session_id: None,
+        }
+    }
+
+    pub fn with_session_id(mut self, value: Option<String>) -> Self {
+        self.session_id = value;
+        self
+    }
+
+    pub fn with_guest_session_id(mut self, value: Option<String>) -> Self {
+        self.guest_session_id = value;
+        self
+    }
+}
+
+impl Command for TVShowDeleteRating {
+    type Output = StatusResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/rating", self.tv_id);
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        crate::util::session_params(self.session_id.as_deref(), self.guest_session_id.as_deref())
+    }
+
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+}
+
+impl SessionCommand for TVShowDeleteRating {
+    fn set_session_id(&mut self, value: Option<String>) {
+        self.session_id = value;
+    }
+
+    fn set_guest_session_id(&mut self, value: Option<String>) {
+        self.guest_session_id = value;
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::rating::Rating;
+    use crate::prelude::Command;
+
+    use super::{TVShowAddRating, TVShowDeleteRating};
+
+    #[tokio::test]
+    async fn add_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("POST", "/tv/1399/rating")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .match_header("content-type", "application/json;charset=utf-8")
+            .match_body(Matcher::Json(serde_json::json!({ "value": 8.5 })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-add-rating.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowAddRating::new(1399, Rating::new(8.5).unwrap())
+            .with_session_id(Some("session".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, 1);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn delete_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("DELETE", "/tv/1399/rating")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("guest_session_id".into(), "guest".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-delete-rating.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowDeleteRating::new(1399)
+            .with_guest_session_id(Some("guest".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, 13);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/tv/1399/rating")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowAddRating::new(1399, Rating::new(8.5).unwrap())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("DELETE", "/tv/1399/rating")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowDeleteRating::new(1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
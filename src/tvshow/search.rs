@@ -73,6 +73,10 @@ impl crate::prelude::Command for TVShowSearch {
         Cow::Borrowed(PATH)
     }
 
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
         let mut res = vec![("query", Cow::Borrowed(self.query.as_str()))];
 
@@ -96,7 +100,15 @@ impl crate::prelude::Command for TVShowSearch {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for TVShowSearch {
+    type Item = super::TVShowShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::TVShowSearch;
     use crate::client::reqwest::ReqwestExecutor;
@@ -132,7 +144,7 @@ mod tests {
         assert!(result.total_pages > 0);
         assert!(result.total_results > 0);
         let item = result.results.first().unwrap();
-        assert_eq!(item.inner.name, "Game of Thrones");
+        assert_eq!(&*item.inner.name, "Game of Thrones");
     }
 
     /// Refering to issue https://github.com/jdrouet/tmdb-api/issues/25
@@ -163,7 +175,7 @@ mod tests {
         assert_eq!(result.total_pages, 1);
         assert_eq!(result.total_results, 2);
         let item = result.results.first().unwrap();
-        assert_eq!(item.inner.name, "Rick and Morty");
+        assert_eq!(&*item.inner.name, "Rick and Morty");
     }
 
     #[tokio::test]
@@ -240,6 +252,6 @@ mod integration_tests {
         assert!(result.total_pages > 0);
         assert!(result.total_results > 1);
         let item = result.results.first().unwrap();
-        assert_eq!(item.inner.name, "The Simpsons");
+        assert_eq!(&*item.inner.name, "The Simpsons");
     }
 }
@@ -0,0 +1,223 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::authentication::client::SessionCommand;
+use crate::common::rating::Rating;
+use crate::prelude::Command;
+
+const PATH: &str = "/tv/{tv_id}/season/{season_number}/account_states";
+
+/// Command to get the ratings given by the user to the episodes of a TV show
+/// season, with a user or a guest session.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::season::account_states::TVShowSeasonAccountStates;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowSeasonAccountStates::new(1399, 1)
+///         .with_session_id(Some("my-session-id".into()));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowSeasonAccountStates {
+    /// ID of the TV Show
+    pub tv_id: u64,
+    /// Number of the season
+    pub season_number: u64,
+    pub session_id: Option<String>,
+    pub guest_session_id: Option<String>,
+}
+
+impl TVShowSeasonAccountStates {
+    pub fn new(tv_id: u64, season_number: u64) -> Self {
+        Self {
+            tv_id,
+            season_number,
+            session_id: None,
+            guest_session_id: None,
+        }
+    }
+
+    pub fn with_session_id(mut self, value: Option<String>) -> Self {
+        self.session_id = value;
+        self
+    }
+
+    pub fn with_guest_session_id(mut self, value: Option<String>) -> Self {
+        self.guest_session_id = value;
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EpisodeAccountState {
+    /// ID of the episode
+    pub id: u64,
+    pub episode_number: u64,
+    /// Rating given by the user, if any.
+    #[serde(with = "crate::util::rated")]
+    pub rated: Option<Rating>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TVShowSeasonAccountStatesResult {
+    /// ID of the season
+    pub id: u64,
+    #[serde(default)]
+    pub results: Vec<EpisodeAccountState>,
+}
+
+impl TVShowSeasonAccountStatesResult {
+    /// Finds the state of the episode with the given number.
+    pub fn episode(&self, episode_number: u64) -> Option<&EpisodeAccountState> {
+        self.results
+            .iter()
+            .find(|item| item.episode_number == episode_number)
+    }
+}
+
+impl Command for TVShowSeasonAccountStates {
+    type Output = TVShowSeasonAccountStatesResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(
+            out,
+            "/tv/{}/season/{}/account_states",
+            self.tv_id, self.season_number
+        );
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        crate::util::session_params(self.session_id.as_deref(), self.guest_session_id.as_deref())
+    }
+}
+
+impl SessionCommand for TVShowSeasonAccountStates {
+    fn set_session_id(&mut self, value: Option<String>) {
+        self.session_id = value;
+    }
+
+    fn set_guest_session_id(&mut self, value: Option<String>) {
+        self.guest_session_id = value;
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::rating::Rating;
+    use crate::prelude::Command;
+
+    use super::TVShowSeasonAccountStates;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/account_states")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../../assets/tv-season-account-states.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = TVShowSeasonAccountStates::new(1399, 1)
+            .with_session_id(Some("session".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 3624);
+        assert_eq!(result.results.len(), 3);
+        assert_eq!(result.episode(1).unwrap().rated, Rating::new(9.0).ok());
+        assert_eq!(result.episode(2).unwrap().rated, None);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/account_states")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowSeasonAccountStates::new(1399, 1)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/account_states")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowSeasonAccountStates::new(1399, 1)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
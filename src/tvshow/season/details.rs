@@ -1,4 +1,11 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::client::{Client, Executor};
+use crate::prelude::Command;
+use crate::tvshow::LazySeason;
+
+const PATH: &str = "/tv/{tv_id}/season/{season_number}";
 
 /// Command to get the details of a tvshow season
 ///
@@ -44,11 +51,32 @@ impl TVShowSeasonDetails {
     }
 }
 
-impl crate::prelude::Command for TVShowSeasonDetails {
+impl TVShowSeasonDetails {
+    /// Same as executing the command, but keeps the episodes as raw JSON to only
+    /// parse them on demand.
+    pub async fn execute_lazy<E: Executor>(
+        &self,
+        client: &Client<E>,
+    ) -> Result<LazySeason, crate::error::Error> {
+        client.execute(&self.path(), self.params()).await
+    }
+}
+
+impl Command for TVShowSeasonDetails {
     type Output = crate::tvshow::Season;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/tv/{}/season/{}", self.tv_id, self.season_number))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/season/{}", self.tv_id, self.season_number);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -60,7 +88,7 @@ impl crate::prelude::Command for TVShowSeasonDetails {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
@@ -95,6 +123,32 @@ mod tests {
         assert_eq!(result.inner.id, 3624);
     }
 
+    #[tokio::test]
+    async fn lazy_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/tv-season-details.json"))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let cmd = TVShowSeasonDetails::new(1399, 1);
+        let lazy = cmd.execute_lazy(&client).await.unwrap();
+        let full = cmd.execute(&client).await.unwrap();
+        assert_eq!(lazy.id, full.inner.id);
+        assert_eq!(lazy.episodes.parse().unwrap(), full.episodes);
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
@@ -1,2 +1,6 @@
 #[cfg(feature = "commands")]
+pub mod account_states;
+#[cfg(feature = "commands")]
+pub mod changes;
+#[cfg(feature = "commands")]
 pub mod details;
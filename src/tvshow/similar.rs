@@ -1,4 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+
+const PATH: &str = "/tv/{tvshow_id}/similar";
 
 /// Command to get similar tvshows
 ///
@@ -53,7 +56,17 @@ impl crate::prelude::Command for GetSimilarTVShows {
     type Output = crate::common::PaginatedResult<super::TVShowShort>;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/tv/{}/similar", self.tvshow_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/similar", self.tvshow_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -70,7 +83,15 @@ impl crate::prelude::Command for GetSimilarTVShows {
     }
 }
 
-#[cfg(test)]
+impl crate::pagination::PaginatedCommand for GetSimilarTVShows {
+    type Item = super::TVShowShort;
+
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::GetSimilarTVShows;
     use crate::client::reqwest::ReqwestExecutor;
@@ -103,7 +124,7 @@ mod tests {
         assert_eq!(result.total_pages, 2074);
         assert_eq!(result.total_results, 41463);
         let item = result.results.first().unwrap();
-        assert_eq!(item.inner.name, "Sunset Vibes");
+        assert_eq!(&*item.inner.name, "Sunset Vibes");
     }
 
     #[tokio::test]
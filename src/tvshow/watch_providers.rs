@@ -1,6 +1,11 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
-use crate::watch_provider::WatchProviderResult;
+use crate::client::{Client, Executor};
+use crate::prelude::Command;
+use crate::watch_provider::{LocatedWatchProvider, WatchProviderOffer, WatchProviderResult};
+
+const PATH: &str = "/tv/{tv_id}/watch/providers";
 
 /// Get a list of watch providers for a TV show.
 ///
@@ -31,13 +36,49 @@ impl TVShowWatchProviders {
     pub fn new(tv_id: u64) -> Self {
         Self { tv_id }
     }
+
+    /// Fetches the watch providers and only keeps the ones available in the given
+    /// country, using its ISO 3166-1 code like `DE`.
+    ///
+    /// Returns `None` when the TV show is not available in that country.
+    pub async fn execute_for_country<E: Executor>(
+        &self,
+        client: &Client<E>,
+        country: &str,
+    ) -> Result<Option<LocatedWatchProvider>, crate::error::Error> {
+        let result = self.execute(client).await?;
+        Ok(result.into_region(country))
+    }
+
+    /// Fetches the watch providers available in the given country, merged
+    /// across the offer types and sorted by display priority.
+    ///
+    /// Returns an empty list when the TV show is not available in that country.
+    pub async fn execute_offers_for_country<E: Executor>(
+        &self,
+        client: &Client<E>,
+        country: &str,
+    ) -> Result<Vec<WatchProviderOffer>, crate::error::Error> {
+        let result = self.execute_for_country(client, country).await?;
+        Ok(result.map(|item| item.offers()).unwrap_or_default())
+    }
 }
 
-impl crate::prelude::Command for TVShowWatchProviders {
+impl Command for TVShowWatchProviders {
     type Output = WatchProviderResult;
 
     fn path(&self) -> Cow<'static, str> {
-        Cow::Owned(format!("/tv/{}/watch/providers", self.tv_id))
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/tv/{}/watch/providers", self.tv_id);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -45,7 +86,7 @@ impl crate::prelude::Command for TVShowWatchProviders {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::TVShowWatchProviders;
     use crate::client::reqwest::ReqwestExecutor;
@@ -79,6 +120,34 @@ mod tests {
         assert!(!result.results.is_empty());
     }
 
+    #[tokio::test]
+    async fn for_country_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/watch/providers")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-watch-providers.json"))
+            .expect(3)
+            .create_async()
+            .await;
+
+        let cmd = TVShowWatchProviders::new(1399);
+        let result = cmd.execute_for_country(&client, "at").await.unwrap();
+        assert!(result.is_some());
+        let result = cmd.execute_for_country(&client, "XX").await.unwrap();
+        assert!(result.is_none());
+        let result = cmd.execute_offers_for_country(&client, "at").await.unwrap();
+        assert!(!result.is_empty());
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
@@ -1 +1,36 @@
 pub(crate) mod empty_string;
+#[cfg(feature = "commands")]
+pub(crate) mod rated;
+pub(crate) mod utc_datetime;
+
+use std::borrow::Cow;
+
+/// Builds the params identifying the user of the commands related to an
+/// account, with a user or a guest session.
+#[cfg(feature = "commands")]
+pub(crate) fn session_params<'a>(
+    session_id: Option<&'a str>,
+    guest_session_id: Option<&'a str>,
+) -> Vec<(&'static str, Cow<'a, str>)> {
+    let mut res = Vec::with_capacity(1);
+    if let Some(session_id) = session_id {
+        res.push(("session_id", Cow::Borrowed(session_id)));
+    }
+    if let Some(guest_session_id) = guest_session_id {
+        res.push(("guest_session_id", Cow::Borrowed(guest_session_id)));
+    }
+    res
+}
+
+/// Truncates a body to the given number of characters, for the error
+/// messages and the executors implementing `Executor::execute_logged`.
+pub fn truncate_body(body: &str, limit: usize) -> Cow<'_, str> {
+    match body.char_indices().nth(limit) {
+        Some((index, _)) => Cow::Owned(format!(
+            "{}... ({} more bytes)",
+            &body[..index],
+            body.len() - index
+        )),
+        None => Cow::Borrowed(body),
+    }
+}
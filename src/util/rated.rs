@@ -0,0 +1,59 @@
+//! (De)serializes the rating of an account state, given as `{"value": 8.0}` when
+//! rated and as `false` otherwise.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::common::rating::Rating;
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum Rated {
+    Rated { value: Rating },
+    NotRated(bool),
+}
+
+pub(crate) fn serialize<S>(value: &Option<Rating>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => Rated::Rated { value: *value },
+        None => Rated::NotRated(false),
+    }
+    .serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Rating>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Rated>::deserialize(deserializer)? {
+        Some(Rated::Rated { value }) => Ok(Some(value)),
+        Some(Rated::NotRated(_)) | None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::rating::Rating;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct State {
+        #[serde(with = "super")]
+        rated: Option<Rating>,
+    }
+
+    #[test]
+    fn should_handle_rated_values() {
+        let state: State = serde_json::from_str(r#"{"rated":{"value":8.5}}"#).unwrap();
+        assert_eq!(state.rated, Some(Rating::new(8.5).unwrap()));
+        assert_eq!(
+            serde_json::to_string(&state).unwrap(),
+            r#"{"rated":{"value":8.5}}"#
+        );
+
+        let state: State = serde_json::from_str(r#"{"rated":false}"#).unwrap();
+        assert_eq!(state.rated, None);
+        assert_eq!(serde_json::to_string(&state).unwrap(), r#"{"rated":false}"#);
+    }
+}
@@ -0,0 +1,56 @@
+//! (De)serializes the dates used by the authentication endpoints, given like
+//! `2016-08-26 17:04:39 UTC`.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+const FORMAT: &str = "%Y-%m-%d %H:%M:%S UTC";
+
+pub(crate) fn serialize<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&value.format(FORMAT).to_string())
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    NaiveDateTime::parse_from_str(&value, FORMAT)
+        .map(|value| value.and_utc())
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TestingStruct {
+        #[serde(with = "super")]
+        value: DateTime<Utc>,
+    }
+
+    #[test]
+    fn should_deserialize() {
+        let result: TestingStruct =
+            serde_json::from_str(r#"{"value":"2016-08-26 17:04:39 UTC"}"#).unwrap();
+        assert_eq!(result.value.to_rfc3339(), "2016-08-26T17:04:39+00:00");
+
+        let result = serde_json::from_str::<TestingStruct>(r#"{"value":"2016-08-26"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_serialize() {
+        let value = TestingStruct {
+            value: "2016-08-26T17:04:39Z".parse().unwrap(),
+        };
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#"{"value":"2016-08-26 17:04:39 UTC"}"#
+        );
+    }
+}
@@ -1,11 +1,14 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt::Write;
 
 use crate::client::Executor;
 use crate::common::MediaType;
 
 use super::WatchProvider;
 
+const PATH: &str = "/watch/providers/{media_type}";
+
 /// Command to list watch providers
 #[derive(Clone, Debug)]
 pub struct WatchProviderList {
@@ -42,6 +45,10 @@ pub struct WatchProviderListResult {
     pub display_priorities: HashMap<String, u64>,
     #[serde(flatten)]
     pub inner: WatchProvider,
+    /// Rejects the fields of the response that aren't supported by this crate.
+    #[cfg(feature = "strict")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::common::strict::DenyUnknownFields,
 }
 
 #[async_trait::async_trait]
@@ -49,7 +56,17 @@ impl crate::prelude::Command for WatchProviderList {
     type Output = Vec<WatchProviderListResult>;
 
     fn path(&self) -> Cow<'static, str> {
-        format!("/watch/providers/{}", self.media_type).into()
+        let mut path = String::new();
+        self.write_path(&mut path);
+        Cow::Owned(path)
+    }
+
+    fn path_template(&self) -> &'static str {
+        PATH
+    }
+
+    fn write_path(&self, out: &mut String) {
+        let _ = write!(out, "/watch/providers/{}", self.media_type);
     }
 
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
@@ -80,7 +97,7 @@ impl crate::prelude::Command for WatchProviderList {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use mockito::Matcher;
 
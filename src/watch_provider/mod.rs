@@ -2,8 +2,11 @@ use std::collections::HashMap;
 
 #[cfg(feature = "commands")]
 pub mod list;
+#[cfg(feature = "commands")]
+pub mod registry;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct WatchProvider {
     pub provider_id: u64,
     pub provider_name: String,
@@ -12,6 +15,7 @@ pub struct WatchProvider {
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct LocatedWatchProvider {
     pub link: String,
     #[serde(default)]
@@ -20,10 +24,128 @@ pub struct LocatedWatchProvider {
     pub rent: Vec<WatchProvider>,
     #[serde(default)]
     pub buy: Vec<WatchProvider>,
+    /// Available for free, with advertisements.
+    #[serde(default)]
+    pub ads: Vec<WatchProvider>,
+    #[serde(default)]
+    pub free: Vec<WatchProvider>,
+}
+
+impl LocatedWatchProvider {
+    /// Merged view of the flatrate, rent, buy, ads and free providers, sorted
+    /// by display priority. A provider available with several offer types only shows up
+    /// once, with all of them.
+    pub fn offers(&self) -> Vec<WatchProviderOffer> {
+        let mut res: Vec<WatchProviderOffer> = Vec::new();
+        let groups = [
+            (OfferType::Flatrate, &self.flatrate),
+            (OfferType::Rent, &self.rent),
+            (OfferType::Buy, &self.buy),
+            (OfferType::Ads, &self.ads),
+            (OfferType::Free, &self.free),
+        ];
+        for (offer_type, providers) in groups {
+            for provider in providers {
+                match res
+                    .iter_mut()
+                    .find(|item| item.inner.provider_id == provider.provider_id)
+                {
+                    Some(existing) => existing.offer_types.push(offer_type),
+                    None => res.push(WatchProviderOffer {
+                        inner: provider.clone(),
+                        offer_types: vec![offer_type],
+                    }),
+                }
+            }
+        }
+        res.sort_by_key(|item| item.inner.display_priority);
+        res
+    }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OfferType {
+    Flatrate,
+    Rent,
+    Buy,
+    Ads,
+    Free,
+}
+
+/// Watch provider with the ways a media can be watched with it.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WatchProviderOffer {
+    #[serde(flatten)]
+    pub inner: WatchProvider,
+    pub offer_types: Vec<OfferType>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct WatchProviderResult {
     pub id: u64,
     pub results: HashMap<String, LocatedWatchProvider>,
 }
+
+impl WatchProviderResult {
+    /// Watch providers available in the given country, using its ISO 3166-1
+    /// code like `DE`.
+    ///
+    /// Returns `None` when the media is not available in that country.
+    pub fn for_region(&self, region: &str) -> Option<&LocatedWatchProvider> {
+        self.results.get(&region.to_ascii_uppercase())
+    }
+
+    /// Same as [WatchProviderResult::for_region], taking the ownership of the
+    /// result to avoid cloning the watch providers.
+    pub fn into_region(mut self, region: &str) -> Option<LocatedWatchProvider> {
+        self.results.remove(&region.to_ascii_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OfferType, WatchProviderResult};
+
+    #[test]
+    fn should_find_region() {
+        let result: WatchProviderResult =
+            serde_json::from_str(include_str!("../../assets/movie-watch-providers.json")).unwrap();
+        let located = result.for_region("at").unwrap();
+        assert_eq!(located.link, result.results["AT"].link);
+        assert!(result.for_region("XX").is_none());
+        assert!(result.clone().into_region("AT").is_some());
+        assert!(result.into_region("XX").is_none());
+    }
+
+    #[test]
+    fn should_merge_offers() {
+        let result: WatchProviderResult =
+            serde_json::from_str(include_str!("../../assets/movie-watch-providers.json")).unwrap();
+        for located in result.results.values() {
+            let offers = located.offers();
+            let count = located.flatrate.len()
+                + located.rent.len()
+                + located.buy.len()
+                + located.ads.len()
+                + located.free.len();
+            let types: usize = offers.iter().map(|item| item.offer_types.len()).sum();
+            assert_eq!(types, count);
+            assert!(offers
+                .windows(2)
+                .all(|pair| pair[0].inner.display_priority <= pair[1].inner.display_priority));
+            for offer in offers.iter() {
+                let id = offer.inner.provider_id;
+                assert_eq!(
+                    offers.iter().filter(|o| o.inner.provider_id == id).count(),
+                    1
+                );
+            }
+        }
+        let offers = result.for_region("US").unwrap().offers();
+        assert!(offers
+            .iter()
+            .any(|item| item.offer_types == [OfferType::Rent, OfferType::Buy]));
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use crate::client::{Client, Executor};
+use crate::common::MediaType;
+use crate::prelude::Command;
+
+use super::list::{WatchProviderList, WatchProviderListResult};
+use super::WatchProvider;
+
+/// In memory registry of the watch providers, to look up their metadata
+/// without fetching it for every title.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::watch_provider::registry::WatchProviderRegistry;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     match WatchProviderRegistry::load(&client, None).await {
+///         Ok(registry) => println!("netflix: {:?}", registry.find_by_name("netflix")),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct WatchProviderRegistry {
+    providers: HashMap<u64, WatchProviderListResult>,
+}
+
+impl WatchProviderRegistry {
+    pub fn new<I: IntoIterator<Item = WatchProviderListResult>>(items: I) -> Self {
+        let mut res = Self::default();
+        res.extend(items);
+        res
+    }
+
+    /// Builds a registry from the movie and the TV show watch providers.
+    pub async fn load<E: Executor>(
+        client: &Client<E>,
+        language: Option<String>,
+    ) -> Result<Self, crate::error::Error> {
+        let mut res = Self::default();
+        for media_type in [MediaType::Movie, MediaType::Tv] {
+            let mut cmd = WatchProviderList::new(media_type);
+            cmd.language = language.clone();
+            res.extend(cmd.execute(client).await?);
+        }
+        Ok(res)
+    }
+
+    /// Adds the given providers, merging the display priorities of the ones
+    /// already known.
+    pub fn extend<I: IntoIterator<Item = WatchProviderListResult>>(&mut self, items: I) {
+        for item in items {
+            match self.providers.get_mut(&item.inner.provider_id) {
+                Some(existing) => {
+                    for (country, priority) in item.display_priorities {
+                        existing
+                            .display_priorities
+                            .entry(country)
+                            .or_insert(priority);
+                    }
+                }
+                None => {
+                    self.providers.insert(item.inner.provider_id, item);
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.providers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    pub fn get(&self, provider_id: u64) -> Option<&WatchProvider> {
+        self.providers.get(&provider_id).map(|item| &item.inner)
+    }
+
+    /// Finds a provider by its name, ignoring the case.
+    pub fn find_by_name(&self, name: &str) -> Option<&WatchProvider> {
+        self.providers
+            .values()
+            .map(|item| &item.inner)
+            .find(|item| item.provider_name.eq_ignore_ascii_case(name))
+    }
+
+    /// Display priority of a provider in the given country.
+    pub fn priority(&self, provider_id: u64, country: &str) -> Option<u64> {
+        self.providers
+            .get(&provider_id)
+            .and_then(|item| item.display_priorities.get(country))
+            .copied()
+    }
+
+    /// Providers available in the given country, ordered by display priority.
+    pub fn for_country(&self, country: &str) -> Vec<&WatchProvider> {
+        let mut res: Vec<_> = self
+            .providers
+            .values()
+            .filter_map(|item| {
+                item.display_priorities
+                    .get(country)
+                    .map(|priority| (*priority, &item.inner))
+            })
+            .collect();
+        res.sort_by_key(|(priority, item)| (*priority, item.provider_id));
+        res.into_iter().map(|(_, item)| item).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &WatchProvider> {
+        self.providers.values().map(|item| &item.inner)
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+
+    use super::WatchProviderRegistry;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _movie = server
+            .mock("GET", "/watch/providers/movie")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/watch-provider-movie-list.json"))
+            .create_async()
+            .await;
+        let _tv = server
+            .mock("GET", "/watch/providers/tv")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/watch-provider-tv-list.json"))
+            .create_async()
+            .await;
+
+        let registry = WatchProviderRegistry::load(&client, None).await.unwrap();
+        assert!(registry.len() > 500);
+        assert_eq!(registry.get(8).unwrap().provider_name, "Netflix");
+        assert_eq!(registry.find_by_name("netflix").unwrap().provider_id, 8);
+        assert!(registry.get(0).is_none());
+        assert_eq!(registry.priority(8, "FR"), Some(1));
+
+        let names: Vec<_> = registry
+            .for_country("FR")
+            .into_iter()
+            .take(3)
+            .map(|item| item.provider_id)
+            .collect();
+        assert_eq!(names, vec![337, 8, 119]);
+        assert!(registry.for_country("XX").is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/watch/providers/movie")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = WatchProviderRegistry::load(&client, None)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}